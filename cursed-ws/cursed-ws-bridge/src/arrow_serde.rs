@@ -0,0 +1,710 @@
+//! Arrow conversion for any serde type.
+//!
+//! The Arrow schema comes from the type, traced once per type with
+//! `serde-reflection`, so every value of a type gets the same schema whatever
+//! it holds. Values are serialized through `serde_json::Value` and mapped onto
+//! Arrow:
+//!
+//! | serde                  | Arrow                                   |
+//! |------------------------|-----------------------------------------|
+//! | `bool`                 | `Boolean`                               |
+//! | `u64`                  | `UInt64`                                |
+//! | other integers         | `Int64`                                 |
+//! | floats                 | `Float64`                               |
+//! | strings, unit variants | `Utf8`                                  |
+//! | structs, maps          | `Struct`                                |
+//! | sequences, tuples      | `Struct` with fields `0`, `1`, ...      |
+//! | `Option<T>`            | nullable `T`, `None` as null            |
+//! | `()`                   | null                                    |
+//!
+//! Sequences become structs so every element gets its own plottable column
+//! (`wheels.0.speed`). Integers are read back from any Arrow integer type and
+//! floats from any float type, so data decoded from other producers still
+//! deserializes.
+//!
+//! Types that can't be traced, such as `serde_json::Value` or enums with
+//! data, fall back to inferring the schema from each value: integers that fit
+//! an `i64` become `Int64`, other numbers `Float64`, and a field that is null
+//! in every row becomes `Null`.
+//!
+//! The mapping is lossy in two places. JSON has no non-finite numbers, so
+//! `NaN` and infinities are written as `NaN`, or as null in an `Option`. An
+//! empty sequence has no fields to tell it from an empty struct and reads back
+//! as `{}`.
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, BooleanArray, Float64Array, Int64Array, NullArray, StringArray, StructArray,
+    UInt64Array,
+};
+use arrow::buffer::NullBuffer;
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, Float64Type, Int64Type, UInt64Type};
+use arrow::error::ArrowError;
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use serde_reflection::{ContainerFormat, Format, Registry, Tracer, TracerConfig, VariantFormat};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Types that can be written as a nested Arrow struct
+pub trait ToArrow {
+    /// A single-row struct array named `name`
+    fn to_arrow_struct(&self, name: &str) -> Result<(FieldRef, ArrayRef), ArrowError>;
+}
+
+/// Types that can be read back from a row of a nested Arrow struct
+pub trait FromArrow: Sized {
+    fn from_arrow_struct(array: &StructArray, row: usize) -> Result<Self, ArrowError>;
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> ToArrow for T {
+    fn to_arrow_struct(&self, name: &str) -> Result<(FieldRef, ArrayRef), ArrowError> {
+        let array = to_arrow_rows(std::slice::from_ref(self))?;
+        let field = Arc::new(Field::new(name, array.data_type().clone(), false));
+        Ok((field, Arc::new(array)))
+    }
+}
+
+impl<T: DeserializeOwned> FromArrow for T {
+    fn from_arrow_struct(array: &StructArray, row: usize) -> Result<Self, ArrowError> {
+        if row >= array.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Row {} out of bounds for a struct with {} rows",
+                row,
+                array.len()
+            )));
+        }
+
+        T::deserialize(Cell { array, row }).map_err(|e| ArrowError::ExternalError(Box::new(e)))
+    }
+}
+
+/// The Arrow layout of a serde type, see the module docs for the mapping
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// Inferred from the values
+    Infer,
+    Null,
+    Boolean,
+    Int64,
+    UInt64,
+    Float64,
+    Utf8,
+    /// `Option`, the inner shape with nulls
+    Nullable(Box<Shape>),
+    /// Named fields in declaration order, tuples use `0`, `1`, ...
+    Struct(Vec<(String, Shape)>),
+    /// Sequences and maps, the field names come from the values
+    Entries(Box<Shape>),
+}
+
+/// Recursive types are inferred past this depth
+const MAX_TRACE_DEPTH: usize = 32;
+
+impl Shape {
+    /// The shape of `T`, traced on first use
+    pub fn of<T: DeserializeOwned + 'static>() -> Arc<Shape> {
+        static SHAPES: OnceLock<Mutex<HashMap<TypeId, Arc<Shape>>>> = OnceLock::new();
+        let shapes = SHAPES.get_or_init(Default::default);
+        if let Some(shape) = shapes.lock().unwrap().get(&TypeId::of::<T>()) {
+            return Arc::clone(shape);
+        }
+
+        let mut tracer = Tracer::new(TracerConfig::default());
+        let shape = match tracer.trace_simple_type::<T>() {
+            Ok((format, _)) => Shape::from_format(&format, &tracer.registry_unchecked(), 0),
+            // `deserialize_any` types like `serde_json::Value`
+            Err(_) => Shape::Infer,
+        };
+        let shape = Arc::new(shape);
+        shapes.lock().unwrap().insert(TypeId::of::<T>(), Arc::clone(&shape));
+        shape
+    }
+
+    fn from_format(format: &Format, registry: &Registry, depth: usize) -> Shape {
+        if depth > MAX_TRACE_DEPTH {
+            return Shape::Infer;
+        }
+        let shape = |format: &Format| Shape::from_format(format, registry, depth + 1);
+        let tuple = |formats: &[Format]| Shape::Struct(
+            formats.iter().enumerate().map(|(index, format)| (index.to_string(), shape(format))).collect(),
+        );
+
+        match format {
+            Format::TypeName(name) => match registry.get(name) {
+                Some(ContainerFormat::UnitStruct) => Shape::Null,
+                Some(ContainerFormat::NewTypeStruct(format)) => shape(format),
+                Some(ContainerFormat::TupleStruct(formats)) => tuple(formats),
+                Some(ContainerFormat::Struct(fields)) => Shape::Struct(
+                    fields.iter().map(|field| (field.name.clone(), shape(&field.value))).collect(),
+                ),
+                // Nested enums may be partly traced, a value of a variant that
+                // wasn't seen falls back to inference when it is written
+                Some(ContainerFormat::Enum(variants))
+                    if variants.values().all(|variant| matches!(variant.value, VariantFormat::Unit)) => Shape::Utf8,
+                _ => Shape::Infer,
+            },
+            Format::Unit => Shape::Null,
+            Format::Bool => Shape::Boolean,
+            Format::I8 | Format::I16 | Format::I32 | Format::I64 | Format::U8 | Format::U16 | Format::U32 => Shape::Int64,
+            Format::U64 => Shape::UInt64,
+            Format::F32 | Format::F64 => Shape::Float64,
+            Format::Char | Format::Str => Shape::Utf8,
+            Format::Option(format) => Shape::Nullable(Box::new(shape(format))),
+            Format::Seq(format) | Format::Map { value: format, .. } => Shape::Entries(Box::new(shape(format))),
+            Format::Tuple(formats) => tuple(formats),
+            Format::TupleArray { content, size } => tuple(&vec![content.as_ref().clone(); *size]),
+            // 128-bit integers, bytes and formats the tracer couldn't resolve
+            _ => Shape::Infer,
+        }
+    }
+
+    /// Whether every value can be written with this shape
+    fn fits(&self, values: &[&Value]) -> bool {
+        !matches!(self, Shape::Infer | Shape::Nullable(_)) && values.iter().all(|value| match (self, value) {
+            (_, Value::Null) => true,
+            (Shape::Boolean, Value::Bool(_)) => true,
+            (Shape::Int64, value) => value.is_i64(),
+            (Shape::UInt64, value) => value.is_u64(),
+            (Shape::Float64, Value::Number(_)) => true,
+            (Shape::Utf8, Value::String(_)) => true,
+            (Shape::Struct(_) | Shape::Entries(_), Value::Object(_) | Value::Array(_)) => true,
+            _ => false,
+        })
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, ArrowError> {
+    serde_json::to_value(value).map_err(|e| ArrowError::ExternalError(Box::new(e)))
+}
+
+/// A single-row struct array named `name` holding `value`, laid out as
+/// `shape`. For values that were already serialized, such as generated
+/// samples, with the shape of the type they came from.
+pub fn shaped_arrow_struct(value: &Value, shape: &Shape, name: &str) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    let array = shaped_rows(&[value], shape)?;
+    let field = Arc::new(Field::new(name, array.data_type().clone(), false));
+    Ok((field, Arc::new(array)))
+}
+
+/// Serialize `rows` into one struct array with a row per element.
+///
+/// Every row has to serialize to a struct or map. Fields missing from some
+/// rows come out as nulls.
+pub fn to_arrow_rows<T: Serialize + DeserializeOwned + 'static>(rows: &[T]) -> Result<StructArray, ArrowError> {
+    let values = rows.iter().map(to_value).collect::<Result<Vec<_>, _>>()?;
+    shaped_rows(&values.iter().collect::<Vec<_>>(), &Shape::of::<T>())
+}
+
+fn shaped_rows(values: &[&Value], shape: &Shape) -> Result<StructArray, ArrowError> {
+    if let Some(value) = values.iter().find(|value| !value.is_object()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected a struct, got {}",
+            kind_name(value)
+        )));
+    }
+    build_struct(values, shape)
+}
+
+fn kind_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a bool",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "a sequence",
+        Value::Object(_) => "a struct",
+    }
+}
+
+/// Build one column from the values of every row, `Value::Null` meaning null.
+///
+/// A null in a float that isn't an `Option` was a non-finite number and is
+/// written as `NaN`.
+fn build_array(values: &[&Value], shape: &Shape) -> Result<(bool, ArrayRef), ArrowError> {
+    let mut shape = shape;
+    let mut nullable = false;
+    while let Shape::Nullable(inner) = shape {
+        shape = inner;
+        nullable = true;
+    }
+    if !shape.fits(values) {
+        return infer_array(values);
+    }
+
+    let array: ArrayRef = match shape {
+        Shape::Null => return Ok((true, Arc::new(NullArray::new(values.len())))),
+        Shape::Boolean => Arc::new(values.iter().map(|value| value.as_bool()).collect::<BooleanArray>()),
+        Shape::Int64 => Arc::new(values.iter().map(|value| value.as_i64()).collect::<Int64Array>()),
+        Shape::UInt64 => Arc::new(values.iter().map(|value| value.as_u64()).collect::<UInt64Array>()),
+        Shape::Float64 => Arc::new(
+            values.iter()
+                .map(|value| match value {
+                    Value::Null if !nullable => Some(f64::NAN),
+                    value => value.as_f64(),
+                })
+                .collect::<Float64Array>(),
+        ),
+        Shape::Utf8 => Arc::new(values.iter().map(|value| value.as_str()).collect::<StringArray>()),
+        Shape::Struct(_) | Shape::Entries(_) => Arc::new(build_struct(values, shape)?),
+        Shape::Infer | Shape::Nullable(_) => unreachable!("never fit"),
+    };
+    Ok((nullable || array.null_count() > 0, array))
+}
+
+/// Build a column of a type that couldn't be traced from its values
+fn infer_array(values: &[&Value]) -> Result<(bool, ArrayRef), ArrowError> {
+    let nullable = values.iter().any(|value| value.is_null());
+    let Some(first) = values.iter().find(|value| !value.is_null()) else {
+        return Ok((true, Arc::new(NullArray::new(values.len()))));
+    };
+
+    let mismatch = values.iter().find(|value| {
+        !value.is_null() && std::mem::discriminant(**value) != std::mem::discriminant(*first)
+    });
+    if let Some(value) = mismatch {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Column mixes {} and {}",
+            kind_name(first),
+            kind_name(value)
+        )));
+    }
+
+    let array: ArrayRef = match first {
+        Value::Null => unreachable!("skipped above"),
+        Value::Bool(_) => Arc::new(values.iter().map(|value| value.as_bool()).collect::<BooleanArray>()),
+        Value::Number(_) => {
+            let all_ints = values.iter().all(|value| value.is_null() || value.is_i64());
+            if all_ints {
+                Arc::new(values.iter().map(|value| value.as_i64()).collect::<Int64Array>())
+            } else {
+                Arc::new(values.iter().map(|value| value.as_f64()).collect::<Float64Array>())
+            }
+        }
+        Value::String(_) => Arc::new(values.iter().map(|value| value.as_str()).collect::<StringArray>()),
+        Value::Array(_) | Value::Object(_) => Arc::new(build_struct(values, &Shape::Infer)?),
+    };
+    Ok((nullable, array))
+}
+
+fn build_struct(values: &[&Value], shape: &Shape) -> Result<StructArray, ArrowError> {
+    let infer = Shape::Infer;
+    let (mut columns, item): (Vec<(String, &Shape)>, &Shape) = match shape {
+        Shape::Struct(fields) => (fields.iter().map(|(name, shape)| (name.clone(), shape)).collect(), &infer),
+        Shape::Entries(item) => (Vec::new(), item),
+        _ => (Vec::new(), &infer),
+    };
+
+    // Then every row's other fields, in first-seen order
+    for value in values {
+        let keys: Vec<String> = match value {
+            Value::Object(map) => map.keys().cloned().collect(),
+            Value::Array(items) => (0..items.len()).map(|index| index.to_string()).collect(),
+            _ => Vec::new(),
+        };
+        for key in keys {
+            if !columns.iter().any(|(name, _)| *name == key) {
+                columns.push((key, item));
+            }
+        }
+    }
+
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut arrays = Vec::with_capacity(columns.len());
+    for (name, shape) in columns {
+        let children: Vec<&Value> = values
+            .iter()
+            .map(|value| match value {
+                Value::Object(map) => map.get(&name).unwrap_or(&Value::Null),
+                Value::Array(items) => name
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| items.get(index))
+                    .unwrap_or(&Value::Null),
+                _ => &Value::Null,
+            })
+            .collect();
+        let (nullable, array) = build_array(&children, shape)?;
+        fields.push(Arc::new(Field::new(name, array.data_type().clone(), nullable)));
+        arrays.push(array);
+    }
+
+    let nulls = values
+        .iter()
+        .any(|value| value.is_null())
+        .then(|| NullBuffer::from(values.iter().map(|value| !value.is_null()).collect::<Vec<_>>()));
+
+    if fields.is_empty() {
+        return Ok(StructArray::new_empty_fields(values.len(), nulls));
+    }
+    StructArray::try_new(Fields::from(fields), arrays, nulls)
+}
+
+/// JSON schema of the `Value`s read back from a `data_type` column
+pub fn json_schema(data_type: &DataType) -> Value {
+    match data_type {
+        DataType::Struct(fields) if is_sequence(fields) => {
+            json!({ "type": "array", "items": json_schema(fields[0].data_type()) })
+        }
+        DataType::Struct(fields) => {
+            let properties: Map<String, Value> = fields.iter()
+                .map(|field| (field.name().clone(), json_schema(field.data_type())))
+                .collect();
+            json!({ "type": "object", "properties": properties })
+        }
+        DataType::Boolean => json!({ "type": "boolean" }),
+        DataType::Utf8 | DataType::LargeUtf8 => json!({ "type": "string" }),
+        data_type if data_type.is_integer() => json!({ "type": "integer" }),
+        data_type if data_type.is_floating() => json!({ "type": "number" }),
+        _ => json!({}),
+    }
+}
+
+/// Structs with fields `0`, `1`, ... are read back as sequences, a struct
+/// without fields as an empty struct
+fn is_sequence(fields: &Fields) -> bool {
+    !fields.is_empty() && fields.iter()
+        .enumerate()
+        .all(|(index, field)| field.name() == &index.to_string())
+}
+
+/// Deserializes one row of any supported column. Reads straight from Arrow
+/// rather than through `serde_json::Value`, so non-finite floats survive.
+#[derive(Clone, Copy)]
+struct Cell<'a> {
+    array: &'a dyn Array,
+    row: usize,
+}
+
+impl<'a> Cell<'a> {
+    fn cast(&self, data_type: &DataType) -> Result<ArrayRef, de::value::Error> {
+        cast(&self.array.slice(self.row, 1), data_type).map_err(de::Error::custom)
+    }
+
+    fn children(self) -> impl Iterator<Item = (&'a str, Cell<'a>)> {
+        let array = self.array.as_struct();
+        let row = self.row;
+        array.fields().iter()
+            .zip(array.columns())
+            .map(move |(field, column)| (field.name().as_str(), Cell { array: column.as_ref(), row }))
+    }
+}
+
+impl<'de> IntoDeserializer<'de, de::value::Error> for Cell<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Cell<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.array.is_null(self.row) {
+            return visitor.visit_unit();
+        }
+
+        match self.array.data_type() {
+            DataType::Null => visitor.visit_unit(),
+            DataType::Boolean => visitor.visit_bool(self.array.as_boolean().value(self.row)),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                visitor.visit_i64(self.cast(&DataType::Int64)?.as_primitive::<Int64Type>().value(0))
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                visitor.visit_u64(self.cast(&DataType::UInt64)?.as_primitive::<UInt64Type>().value(0))
+            }
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+                visitor.visit_f64(self.cast(&DataType::Float64)?.as_primitive::<Float64Type>().value(0))
+            }
+            DataType::Utf8 => visitor.visit_str(self.array.as_string::<i32>().value(self.row)),
+            DataType::LargeUtf8 => visitor.visit_str(self.array.as_string::<i64>().value(self.row)),
+            DataType::Struct(fields) if is_sequence(fields) => {
+                let mut items = SeqDeserializer::new(self.children().map(|(_, cell)| cell));
+                let value = visitor.visit_seq(&mut items)?;
+                items.end()?;
+                Ok(value)
+            }
+            DataType::Struct(_) => {
+                let mut entries = MapDeserializer::new(self.children());
+                let value = visitor.visit_map(&mut entries)?;
+                entries.end()?;
+                Ok(value)
+            }
+            other => Err(de::Error::custom(format!("Reading {} columns into serde types is not supported", other))),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.array.is_null(self.row) || self.array.data_type() == &DataType::Null {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants are strings, others a struct with the variant's name as
+    /// its only field, like `serde_json` writes them
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.array.is_null(self.row) {
+            return self.deserialize_any(visitor);
+        }
+        match self.array.data_type() {
+            DataType::Utf8 => visitor.visit_enum(self.array.as_string::<i32>().value(self.row).into_deserializer()),
+            DataType::LargeUtf8 => visitor.visit_enum(self.array.as_string::<i64>().value(self.row).into_deserializer()),
+            DataType::Struct(fields) if fields.len() == 1 => {
+                visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(self.children())))
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Mode {
+        Idle,
+        Driving,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Wheel {
+        speed: f64,
+        slipping: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Robot {
+        name: String,
+        count: i64,
+        big: u64,
+        mode: Mode,
+        position: (f64, f64, f64),
+        wheels: Vec<Wheel>,
+        battery: Option<f64>,
+        tags: BTreeMap<String, String>,
+    }
+
+    fn robot() -> Robot {
+        Robot {
+            name: "r2".to_string(),
+            count: -3,
+            big: 1 << 40,
+            mode: Mode::Driving,
+            position: (1.0, 2.5, -0.5),
+            wheels: vec![Wheel { speed: 0.5, slipping: false }, Wheel { speed: 0.75, slipping: true }],
+            battery: None,
+            tags: BTreeMap::from([("team".to_string(), "blue".to_string())]),
+        }
+    }
+
+    fn round_trip<T: Serialize + DeserializeOwned + 'static>(value: &T) -> T {
+        let (_, array) = value.to_arrow_struct("data").unwrap();
+        T::from_arrow_struct(array.as_struct(), 0).unwrap()
+    }
+
+    #[test]
+    fn struct_round_trips() {
+        assert_eq!(round_trip(&robot()), robot());
+    }
+
+    #[test]
+    fn struct_maps_onto_arrow_types() {
+        let (field, array) = robot().to_arrow_struct("data").unwrap();
+        assert_eq!(field.name(), "data");
+        let DataType::Struct(fields) = array.data_type() else {
+            panic!("expected a struct, got {}", array.data_type());
+        };
+        let types: Vec<(&str, &DataType)> = fields.iter()
+            .map(|field| (field.name().as_str(), field.data_type()))
+            .collect();
+        assert_eq!(types[0], ("name", &DataType::Utf8));
+        assert_eq!(types[1], ("count", &DataType::Int64));
+        assert_eq!(types[2], ("big", &DataType::UInt64));
+        assert_eq!(types[3], ("mode", &DataType::Utf8));
+        assert_eq!(types[6], ("battery", &DataType::Float64));
+        assert!(fields[6].is_nullable());
+        assert!(!fields[1].is_nullable());
+
+        let position = fields.find("position").unwrap().1;
+        let DataType::Struct(items) = position.data_type() else {
+            panic!("tuples are structs");
+        };
+        let names: Vec<&str> = items.iter().map(|field| field.name().as_str()).collect();
+        assert_eq!(names, ["0", "1", "2"]);
+
+        // Untraced integers that don't fit an i64 become floats
+        let array = to_arrow_rows(&[json!({ "big": u64::MAX })]).unwrap();
+        assert_eq!(array.column(0).data_type(), &DataType::Float64);
+    }
+
+    #[test]
+    fn rows_with_missing_fields_are_null() {
+        let rows = [json!({ "a": 1, "b": "x" }), json!({ "a": 2 }), json!({ "a": null, "c": 1.5 })];
+        let array = to_arrow_rows(&rows).unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(Value::from_arrow_struct(&array, 0).unwrap(), json!({ "a": 1, "b": "x", "c": null }));
+        assert_eq!(Value::from_arrow_struct(&array, 1).unwrap(), json!({ "a": 2, "b": null, "c": null }));
+        assert_eq!(Value::from_arrow_struct(&array, 2).unwrap(), json!({ "a": null, "b": null, "c": 1.5 }));
+    }
+
+    #[test]
+    fn rows_must_be_structs_of_one_type_per_column() {
+        assert!(to_arrow_rows(&[1, 2]).is_err());
+        assert!(to_arrow_rows(&[json!({ "a": 1 }), json!({ "a": "x" })]).is_err());
+    }
+
+    #[test]
+    fn empty_struct_reads_back_as_a_struct() {
+        let value: Value = round_trip(&json!({ "empty": {}, "list": [1] }));
+        assert_eq!(value, json!({ "empty": {}, "list": [1] }));
+    }
+
+    #[test]
+    fn options_keep_one_schema_whether_set_or_not() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Reading {
+            value: Option<f64>,
+            label: Option<String>,
+        }
+        let unset = Reading { value: None, label: None };
+        let set = Reading { value: Some(1.5), label: Some("ok".to_string()) };
+        let (unset_field, _) = unset.to_arrow_struct("data").unwrap();
+        let (set_field, _) = set.to_arrow_struct("data").unwrap();
+        assert_eq!(unset_field, set_field);
+        assert_eq!(round_trip(&unset), unset);
+        assert_eq!(round_trip(&set), set);
+    }
+
+    #[test]
+    fn non_finite_floats_are_written_as_nan() {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct Reading {
+            value: f64,
+            optional: Option<f64>,
+        }
+        let finite = Reading { value: 1.0, optional: Some(1.0) }.to_arrow_struct("data").unwrap();
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let (field, array) = Reading { value, optional: Some(value) }.to_arrow_struct("data").unwrap();
+            assert_eq!(field, finite.0);
+            // JSON can't tell an `Option` holding a non-finite float from `None`
+            let read = Reading::from_arrow_struct(array.as_struct(), 0).unwrap();
+            assert!(read.value.is_nan());
+            assert_eq!(read.optional, None);
+        }
+
+        // Non-finite floats from other producers read back as they are
+        let array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("value", DataType::Float64, false)),
+                Arc::new(Float64Array::from(vec![f64::INFINITY])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("optional", DataType::Float64, true)),
+                Arc::new(Float64Array::from(vec![f64::NAN])) as ArrayRef,
+            ),
+        ]);
+        let read = Reading::from_arrow_struct(&array, 0).unwrap();
+        assert_eq!(read.value, f64::INFINITY);
+        assert!(read.optional.unwrap().is_nan());
+        assert_eq!(Value::from_arrow_struct(&array, 0).unwrap(), json!({ "value": null, "optional": null }));
+    }
+
+    #[test]
+    fn enums_with_data_are_inferred() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum Command {
+            Stop,
+            Drive { speed: f64 },
+        }
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Message {
+            command: Command,
+        }
+        assert_eq!(*Shape::of::<Command>(), Shape::Infer);
+        for command in [Command::Stop, Command::Drive { speed: 2.0 }] {
+            let message = Message { command };
+            assert_eq!(round_trip(&message), message);
+        }
+    }
+
+    #[test]
+    fn shapes_follow_the_type() {
+        assert_eq!(*Shape::of::<Value>(), Shape::Infer);
+        assert_eq!(*Shape::of::<Mode>(), Shape::Utf8);
+        assert_eq!(
+            *Shape::of::<Wheel>(),
+            Shape::Struct(vec![("speed".to_string(), Shape::Float64), ("slipping".to_string(), Shape::Boolean)])
+        );
+        assert_eq!(
+            *Shape::of::<Option<[u8; 2]>>(),
+            Shape::Nullable(Box::new(Shape::Struct(vec![
+                ("0".to_string(), Shape::Int64),
+                ("1".to_string(), Shape::Int64),
+            ])))
+        );
+        assert_eq!(*Shape::of::<Vec<u64>>(), Shape::Entries(Box::new(Shape::UInt64)));
+    }
+
+    #[test]
+    fn other_integer_and_float_widths_are_read() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Sample {
+            small: i64,
+            unsigned: u64,
+            half: f64,
+        }
+        let array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("small", DataType::Int8, false)),
+                Arc::new(arrow::array::Int8Array::from(vec![-7])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("unsigned", DataType::UInt64, false)),
+                Arc::new(arrow::array::UInt64Array::from(vec![u64::MAX])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("half", DataType::Float32, false)),
+                Arc::new(arrow::array::Float32Array::from(vec![0.5])) as ArrayRef,
+            ),
+        ]);
+        assert_eq!(
+            Sample::from_arrow_struct(&array, 0).unwrap(),
+            Sample { small: -7, unsigned: u64::MAX, half: 0.5 }
+        );
+        assert!(Sample::from_arrow_struct(&array, 1).is_err());
+    }
+
+    #[test]
+    fn json_schema_describes_sequences_and_structs() {
+        let (_, array) = robot().to_arrow_struct("data").unwrap();
+        let schema = json_schema(array.data_type());
+        assert_eq!(schema["properties"]["count"], json!({ "type": "integer" }));
+        assert_eq!(schema["properties"]["position"], json!({ "type": "array", "items": { "type": "number" } }));
+        assert_eq!(schema["properties"]["wheels"]["items"]["properties"]["slipping"], json!({ "type": "boolean" }));
+        assert_eq!(json_schema(&DataType::Struct(Fields::empty())), json!({ "type": "object", "properties": {} }));
+    }
+}
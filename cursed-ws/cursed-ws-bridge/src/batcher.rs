@@ -0,0 +1,171 @@
+use arrow::compute::concat_batches;
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use std::collections::BTreeMap;
+
+/// When a connection flushes the rows it has accumulated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlushPolicy{
+    /// Flush window in ms, `0` sends every batch as soon as it arrives
+    pub interval_ms: u64,
+    /// Flush a topic early once it has this many rows pending
+    pub max_rows: usize,
+}
+
+impl Default for FlushPolicy{
+    fn default() -> Self{
+        Self{
+            interval_ms: 20,
+            max_rows: 1024,
+        }
+    }
+}
+
+impl FlushPolicy{
+    pub fn is_immediate(&self) -> bool{
+        self.interval_ms == 0 || self.max_rows <= 1
+    }
+}
+
+#[derive(Debug)]
+struct PendingRows{
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+    rows: usize,
+}
+
+impl PendingRows{
+    fn concat(self) -> Result<RecordBatch, ArrowError>{
+        concat_batches(&self.schema, &self.batches)
+    }
+}
+
+/// Accumulates rows per topic so they go out as one multi-row batch
+#[derive(Debug, Default)]
+pub struct TopicBatcher{
+    policy: FlushPolicy,
+    pending: BTreeMap<String, PendingRows>,
+}
+
+impl TopicBatcher{
+    pub fn new(policy: FlushPolicy) -> Self{
+        Self{
+            policy,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Queue a batch for `topic`, returning anything that has to go out now.
+    ///
+    /// Pending rows are flushed early when the topic's schema changes or it
+    /// reaches `max_rows`.
+    pub fn push(&mut self, topic: &str, batch: RecordBatch) -> Result<Vec<(String, RecordBatch)>, ArrowError>{
+        if self.policy.is_immediate(){
+            return Ok(vec![(topic.to_string(), batch)]);
+        }
+
+        let mut ready = Vec::new();
+
+        let schema_changed = self.pending.get(topic)
+            .is_some_and(|pending| pending.schema != batch.schema());
+        if schema_changed{
+            let pending = self.pending.remove(topic).expect("checked above");
+            ready.push((topic.to_string(), pending.concat()?));
+        }
+
+        let pending = self.pending.entry(topic.to_string()).or_insert_with(|| PendingRows{
+            schema: batch.schema(),
+            batches: Vec::new(),
+            rows: 0,
+        });
+        pending.rows += batch.num_rows();
+        pending.batches.push(batch);
+
+        if pending.rows >= self.policy.max_rows{
+            let pending = self.pending.remove(topic).expect("inserted above");
+            ready.push((topic.to_string(), pending.concat()?));
+        }
+
+        Ok(ready)
+    }
+
+    /// Take every pending topic as a single batch each
+    pub fn flush(&mut self) -> Result<Vec<(String, RecordBatch)>, ArrowError>{
+        std::mem::take(&mut self.pending)
+            .into_iter()
+            .map(|(topic, pending)| Ok((topic, pending.concat()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use arrow::array::{Float64Array, Int64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn floats(values: &[f64]) -> RecordBatch{
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, false)]));
+        RecordBatch::try_new(schema, vec![Arc::new(Float64Array::from(values.to_vec()))]).unwrap()
+    }
+
+    fn ints(values: &[i64]) -> RecordBatch{
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Int64, false)]));
+        RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(values.to_vec()))]).unwrap()
+    }
+
+    fn batcher(interval_ms: u64, max_rows: usize) -> TopicBatcher{
+        TopicBatcher::new(FlushPolicy{ interval_ms, max_rows })
+    }
+
+    #[test]
+    fn rows_wait_for_the_flush_window(){
+        let mut batcher = batcher(20, 1024);
+        assert!(batcher.push("a", floats(&[1.0])).unwrap().is_empty());
+        assert!(batcher.push("a", floats(&[2.0, 3.0])).unwrap().is_empty());
+        assert!(batcher.push("b", floats(&[4.0])).unwrap().is_empty());
+
+        let flushed = batcher.flush().unwrap();
+        let rows: Vec<_> = flushed.iter().map(|(topic, batch)| (topic.as_str(), batch.num_rows())).collect();
+        assert_eq!(rows, [("a", 3), ("b", 1)]);
+        assert!(batcher.flush().unwrap().is_empty());
+    }
+
+    #[test]
+    fn topic_flushes_early_at_max_rows(){
+        let mut batcher = batcher(20, 3);
+        assert!(batcher.push("a", floats(&[1.0, 2.0])).unwrap().is_empty());
+        assert!(batcher.push("b", floats(&[1.0])).unwrap().is_empty());
+
+        let ready = batcher.push("a", floats(&[3.0])).unwrap();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].0, "a");
+        assert_eq!(ready[0].1, floats(&[1.0, 2.0, 3.0]));
+
+        // Only the other topic is left for the timer
+        let flushed = batcher.flush().unwrap();
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].0, "b");
+    }
+
+    #[test]
+    fn schema_change_flushes_pending_rows_first(){
+        let mut batcher = batcher(20, 1024);
+        assert!(batcher.push("a", floats(&[1.0])).unwrap().is_empty());
+
+        let ready = batcher.push("a", ints(&[2])).unwrap();
+        assert_eq!(ready, [("a".to_string(), floats(&[1.0]))]);
+        assert_eq!(batcher.flush().unwrap(), [("a".to_string(), ints(&[2]))]);
+    }
+
+    #[test]
+    fn immediate_policy_passes_batches_through(){
+        for mut batcher in [batcher(0, 1024), batcher(20, 1)]{
+            let ready = batcher.push("a", floats(&[1.0, 2.0])).unwrap();
+            assert_eq!(ready, [("a".to_string(), floats(&[1.0, 2.0]))]);
+            assert!(batcher.flush().unwrap().is_empty());
+        }
+    }
+}
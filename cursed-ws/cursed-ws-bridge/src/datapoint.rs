@@ -1,9 +1,11 @@
 use crate::ws_handler::WSMessage;
-use crate::test_data::TestData;
-use arrow::array::{Array, ArrayRef, StringArray, UInt64Array};
-use arrow::datatypes::{DataType, Field, Schema};
+use crate::arrow_serde::{FromArrow, ToArrow};
+use crate::flatten::{flatten_struct, unflatten_columns};
+use arrow::array::{Array, ArrayRef, StringArray, StructArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, FieldRef, Schema};
+use arrow::error::ArrowError;
+use arrow::ipc::reader::{FileReader, StreamReader};
 use arrow::record_batch::RecordBatch;
-use arrow::ipc::writer::FileWriter;
 use std::sync::Arc;
 use std::io::Cursor;
 use serde_with::skip_serializing_none;
@@ -40,155 +42,158 @@ impl DataPoint {
         }
     }
 
-    pub fn from_test_data(topic: String, time: u64, test_data: &TestData) -> Self {
-        // Convert the TestData to a struct array
-        let (_, arrow_data) = test_data.to_arrow_struct("data");
-        
-        Self {
+    pub fn from_data<T: ToArrow>(topic: String, time: u64, data: &T) -> Result<Self, ArrowError> {
+        // Convert the data to a struct array
+        let (_, arrow_data) = data.to_arrow_struct("data")?;
+
+        Ok(Self {
             topic,
             time,
             arrow_data: Some(arrow_data),
             data_json: None,
-        }
+        })
     }
 
     pub fn message(&self) -> WSMessage {
         WSMessage::NewDatapoint(self.clone())
     }
 
-    pub fn to_record_batch(&self) -> Result<RecordBatch, arrow::error::ArrowError> {
-        // Ensure we have arrow data
-        let arrow_data = match &self.arrow_data {
-            Some(data) => Arc::clone(data),
+    /// One-row batch of `topic`, `time` and the payload's leaf columns.
+    ///
+    /// Struct payloads of any shape are flattened into dotted columns such as
+    /// `pose.position.x`, [`DataPoint::from_record_batch`] rebuilds them. Other
+    /// payloads become a single `data` column.
+    pub fn to_flattened_record_batch(&self) -> Result<RecordBatch, arrow::error::ArrowError> {
+        let data_columns: Vec<(FieldRef, ArrayRef)> = match &self.arrow_data {
+            Some(data) => match data.as_any().downcast_ref::<StructArray>() {
+                Some(data) => flatten_struct(data),
+                None => vec![(
+                    Arc::new(Field::new("data", data.data_type().clone(), data.null_count() > 0)),
+                    Arc::clone(data),
+                )],
+            },
             None => {
-                // If we only have JSON, convert it to a string array
                 let json = self.data_json.clone().unwrap_or_default();
-                Arc::new(StringArray::from(vec![json])) as Arc<dyn Array>
+                vec![(
+                    Arc::new(Field::new("data", DataType::Utf8, false)),
+                    Arc::new(StringArray::from(vec![json])) as ArrayRef,
+                )]
             }
         };
 
-        // Create schema for the record batch
-        let schema = Schema::new(vec![
-            Field::new("topic", DataType::Utf8, false),
-            Field::new("time", DataType::UInt64, false),
-            Field::new("data", arrow_data.data_type().clone(), false),
-        ]);
-
-        // Create arrays for each column
-        let topic_array = Arc::new(StringArray::from(vec![self.topic.clone()]));
-        let time_array = Arc::new(UInt64Array::from(vec![self.time]));
-
-        // Combine arrays into a record batch
-        RecordBatch::try_new(
-            Arc::new(schema),
-            vec![
-                topic_array,
-                time_array,
-                arrow_data,
-            ],
-        )
-    }
-
-    pub fn to_flattened_record_batch(&self, test_data: &TestData) -> Result<RecordBatch, arrow::error::ArrowError> {
-        // Get flattened arrays from TestData
-        let flattened_arrays = test_data.to_flattened_arrays();
-        
-        // Create schema fields
-        let mut fields = vec![
-            Field::new("topic", DataType::Utf8, false),
-            Field::new("time", DataType::UInt64, false),
+        let mut fields: Vec<FieldRef> = vec![
+            Arc::new(Field::new("topic", DataType::Utf8, false)),
+            Arc::new(Field::new("time", DataType::UInt64, false)),
         ];
-        
-        // Add fields for each flattened array
-        for (name, array) in &flattened_arrays {
-            fields.push(Field::new(name, array.data_type().clone(), false));
-        }
-        
-        let schema = Schema::new(fields);
-        
-        // Create topic and time arrays
-        let topic_array = Arc::new(StringArray::from(vec![self.topic.clone()]));
-        let time_array = Arc::new(UInt64Array::from(vec![self.time]));
-        
-        // Combine all arrays
         let mut column_arrays: Vec<ArrayRef> = vec![
-            topic_array,
-            time_array,
+            Arc::new(StringArray::from(vec![self.topic.clone()])),
+            Arc::new(UInt64Array::from(vec![self.time])),
         ];
-        
-        // Add arrays for each flattened field
-        for (_, array) in flattened_arrays {
+        for (field, array) in data_columns {
+            fields.push(field);
             column_arrays.push(array);
         }
-        
-        RecordBatch::try_new(Arc::new(schema), column_arrays)
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), column_arrays)
     }
 
-    pub fn to_ipc_bytes(&self, test_data: &TestData) -> Result<Vec<u8>, arrow::error::ArrowError> {
-        let batch = self.to_flattened_record_batch(test_data)?;
-        let schema = batch.schema();
-        
-        // Create buffer to write IPC format
-        let mut buffer = Vec::new();
-        {
-            let mut writer = FileWriter::try_new(&mut buffer, &schema)?;
-            writer.write(&batch)?;
-            writer.finish()?;
+    /// Decode every row of an Arrow IPC file or stream into DataPoints.
+    ///
+    /// Columns other than `topic` and `time` are rebuilt into nested structs
+    /// from their dotted names and kept as each point's `arrow_data`.
+    pub fn from_ipc_bytes(bytes: &[u8]) -> Result<Vec<Self>, ArrowError> {
+        let batches = if bytes.starts_with(ARROW_FILE_MAGIC) {
+            FileReader::try_new(Cursor::new(bytes), None)?.collect::<Result<Vec<_>, _>>()?
+        } else {
+            StreamReader::try_new(Cursor::new(bytes), None)?.collect::<Result<Vec<_>, _>>()?
+        };
+
+        let mut datapoints = Vec::new();
+        for batch in &batches {
+            datapoints.extend(Self::from_record_batch(batch)?);
         }
-        
-        Ok(buffer)
+        Ok(datapoints)
     }
 
-    // Create DataPoint from IPC bytes
-    pub fn from_ipc_bytes(bytes: &[u8]) -> Result<(Self, TestData), arrow::error::ArrowError> {
-        use arrow::ipc::reader::FileReader;
-        
-        let cursor = Cursor::new(bytes);
-        let mut reader = FileReader::try_new(cursor, None)?;
-        
-        // Read the first record batch
-        if let Some(batch) = reader.next() {
-            let batch = batch?;
-            
-            // Extract topic and time
-            let topic_array = batch.column(0).as_any().downcast_ref::<StringArray>()
-                .ok_or_else(|| arrow::error::ArrowError::InvalidArgumentError(
-                    "Expected topic column to be StringArray".to_string()
-                ))?;
-            
-            let time_array = batch.column(1).as_any().downcast_ref::<UInt64Array>()
-                .ok_or_else(|| arrow::error::ArrowError::InvalidArgumentError(
-                    "Expected time column to be UInt64Array".to_string()
-                ))?;
-            
-            if topic_array.len() == 0 || time_array.len() == 0 {
-                return Err(arrow::error::ArrowError::InvalidArgumentError(
-                    "Empty topic or time array".to_string()
-                ));
-            }
-            
-            let topic = topic_array.value(0).to_string();
-            let time = time_array.value(0);
-            
-            // We would need to implement the conversion back to TestData
-            // This is a placeholder implementation
-            let test_data = TestData::new_sin(0.0); // Placeholder
-            
-            // Create a placeholder arrow_data from the test_data
-            let (_, arrow_data) = test_data.to_arrow_struct("data");
-            
-            let datapoint = DataPoint {
-                topic,
-                time,
-                arrow_data: Some(arrow_data),
-                data_json: None,
-            };
-            
-            Ok((datapoint, test_data))
-        } else {
-            Err(arrow::error::ArrowError::InvalidArgumentError(
-                "No record batch found in IPC bytes".to_string()
+    /// Split a `topic`, `time`, data columns batch into one DataPoint per row
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Vec<Self>, ArrowError> {
+        let topic_array = topic_column(batch)?;
+        let time_array = time_column(batch)?;
+
+        let schema = batch.schema();
+        let columns = schema.fields().iter()
+            .zip(batch.columns())
+            .filter(|(field, _)| field.name() != "topic" && field.name() != "time")
+            .map(|(field, array)| (Arc::clone(field), Arc::clone(array)));
+        let data = unflatten_columns(columns, batch.num_rows())?;
+
+        Ok((0..batch.num_rows())
+            .map(|row| DataPoint::new(
+                topic_array.value(row).to_string(),
+                time_array.value(row),
+                Arc::new(data.slice(row, 1)),
             ))
-        }
+            .collect())
+    }
+
+    /// Read the Arrow payload back as `T`
+    pub fn to_data<T: FromArrow>(&self) -> Result<T, ArrowError> {
+        let data = self.arrow_data.as_ref()
+            .and_then(|data| data.as_any().downcast_ref::<StructArray>())
+            .ok_or_else(|| ArrowError::InvalidArgumentError(
+                "DataPoint has no struct payload".to_string()
+            ))?;
+
+        T::from_arrow_struct(data, 0)
+    }
+}
+
+const ARROW_FILE_MAGIC: &[u8] = b"ARROW1";
+
+fn topic_column(batch: &RecordBatch) -> Result<&StringArray, ArrowError> {
+    batch.column_by_name("topic")
+        .and_then(|column| column.as_any().downcast_ref::<StringArray>())
+        .ok_or_else(|| ArrowError::InvalidArgumentError(
+            "Expected a Utf8 topic column".to_string()
+        ))
+}
+
+fn time_column(batch: &RecordBatch) -> Result<&UInt64Array, ArrowError> {
+    batch.column_by_name("time")
+        .and_then(|column| column.as_any().downcast_ref::<UInt64Array>())
+        .ok_or_else(|| ArrowError::InvalidArgumentError(
+            "Expected a UInt64 time column".to_string()
+        ))
+}
+
+/// Time of the first row in a batch, read from its `time` column
+pub fn batch_time(batch: &RecordBatch) -> Result<u64, ArrowError> {
+    let time_array = time_column(batch)?;
+
+    if time_array.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Empty time column".to_string()
+        ));
     }
-}
\ No newline at end of file
+
+    Ok(time_array.value(0))
+}
+
+/// Earliest and latest time in a batch's `time` column, `None` when empty
+pub fn batch_time_range(batch: &RecordBatch) -> Result<Option<(u64, u64)>, ArrowError> {
+    let time_array = time_column(batch)?;
+
+    let start = arrow::compute::min(time_array);
+    let end = arrow::compute::max(time_array);
+    Ok(start.zip(end))
+}
+
+/// Type of the payload the data columns of a batch are rebuilt into, read
+/// from its first row
+pub fn batch_data_type(batch: &RecordBatch) -> Result<DataType, ArrowError> {
+    let first_row = batch.slice(0, batch.num_rows().min(1));
+    Ok(DataPoint::from_record_batch(&first_row)?
+        .first()
+        .and_then(|datapoint| datapoint.arrow_data.as_ref().map(|data| data.data_type().clone()))
+        .unwrap_or(DataType::Null))
+}
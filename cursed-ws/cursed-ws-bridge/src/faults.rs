@@ -0,0 +1,345 @@
+//! Impairments applied to generated topics so clients can be tested against
+//! the kind of data real sensors produce.
+//!
+//! Value faults (noise, bias drift, spikes) touch every floating point field.
+//! Delivery faults (drops, duplicates, reordering, latency bursts) act on
+//! whole samples. Every probability is per sample and `0` by default, so an
+//! empty config leaves a topic clean.
+
+use crate::state::Payload;
+use arrow::array::{ArrayRef, AsArray, Float64Array};
+use arrow::datatypes::{DataType, Float64Type};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand_distr::StandardNormal;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct FaultConfig {
+    /// Standard deviation of white noise added to every value
+    pub noise_std: f64,
+    /// Standard deviation per √s of a random-walk bias added to every value
+    pub bias_drift_std: f64,
+    /// Chance of a value being replaced by an outlier, see [`SPIKE_GAIN`]
+    pub spike_probability: f64,
+    /// Chance of a sample not being published
+    pub drop_probability: f64,
+    /// Chance of a sample being published twice with the same timestamp
+    pub duplicate_probability: f64,
+    /// Chance of a sample being held back and published after the next one
+    pub reorder_probability: f64,
+    /// Chance of a latency burst starting at a sample
+    pub burst_probability: f64,
+    /// Samples during a burst are held and then published together
+    pub burst_duration_ms: u64,
+}
+
+impl FaultConfig {
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let probabilities = [
+            ("spike_probability", self.spike_probability),
+            ("drop_probability", self.drop_probability),
+            ("duplicate_probability", self.duplicate_probability),
+            ("reorder_probability", self.reorder_probability),
+            ("burst_probability", self.burst_probability),
+        ];
+        for (name, probability) in probabilities {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(format!("{} must be between 0 and 1", name));
+            }
+        }
+        if !(self.noise_std >= 0.0 && self.bias_drift_std >= 0.0) {
+            return Err("noise_std and bias_drift_std must not be negative".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Spikes are this many times the value they replace, or at least this big,
+/// with a random sign. They stay finite so JSON and Arrow clients see the
+/// same outlier.
+pub const SPIKE_GAIN: f64 = 1000.0;
+
+/// A sample waiting to be published
+type Sample = (String, u64, Payload);
+
+/// Applies one topic config's faults, keeping the state they need between samples
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    config: FaultConfig,
+    /// Current bias per `topic` and dotted column
+    bias: HashMap<(String, String), f64>,
+    /// Sample per topic held back to be published after the next one
+    held: HashMap<String, Sample>,
+    /// Samples held by the current latency burst
+    delayed: Vec<Sample>,
+    burst_end: Option<u64>,
+}
+
+impl FaultInjector {
+    pub fn new(config: FaultConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    pub fn should_drop(&self, rng: &mut StdRng) -> bool {
+        rng.gen_bool(self.config.drop_probability)
+    }
+
+    fn corrupt_number(&mut self, topic: &str, column: &str, value: f64, dt: f64, rng: &mut StdRng) -> f64 {
+        if rng.gen_bool(self.config.spike_probability) {
+            let sign = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+            return sign * SPIKE_GAIN * value.abs().clamp(1.0, f64::MAX / SPIKE_GAIN);
+        }
+
+        let mut value = value;
+        if self.config.bias_drift_std > 0.0 {
+            let bias = self.bias.entry((topic.to_string(), column.to_string())).or_default();
+            let step: f64 = rng.sample(StandardNormal);
+            *bias += step * self.config.bias_drift_std * dt.sqrt();
+            value += *bias;
+        }
+        if self.config.noise_std > 0.0 {
+            let noise: f64 = rng.sample(StandardNormal);
+            value += noise * self.config.noise_std;
+        }
+        value
+    }
+
+    /// Corrupt the floating point fields of a JSON value
+    pub fn corrupt_value(&mut self, topic: &str, value: &mut Value, dt: f64, rng: &mut StdRng) {
+        self.corrupt_value_at(topic, "", value, dt, rng);
+    }
+
+    fn corrupt_value_at(&mut self, topic: &str, path: &str, value: &mut Value, dt: f64, rng: &mut StdRng) {
+        let join = |key: &str| if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) };
+        match value {
+            Value::Number(number) if number.is_f64() => {
+                let corrupted = self.corrupt_number(topic, path, number.as_f64().unwrap_or_default(), dt, rng);
+                *value = Value::from(corrupted);
+            }
+            Value::Object(map) => {
+                for (key, child) in map.iter_mut() {
+                    self.corrupt_value_at(topic, &join(key), child, dt, rng);
+                }
+            }
+            Value::Array(items) => {
+                for (index, child) in items.iter_mut().enumerate() {
+                    self.corrupt_value_at(topic, &join(&index.to_string()), child, dt, rng);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Corrupt every `Float64` column of a flattened batch
+    pub fn corrupt_batch(&mut self, topic: &str, batch: RecordBatch, dt: f64, rng: &mut StdRng) -> Result<RecordBatch, ArrowError> {
+        let schema = batch.schema();
+        let mut columns = Vec::with_capacity(batch.num_columns());
+        for (field, column) in schema.fields().iter().zip(batch.columns()) {
+            if field.data_type() != &DataType::Float64 {
+                columns.push(Arc::clone(column));
+                continue;
+            }
+
+            let corrupted: Float64Array = column
+                .as_primitive::<Float64Type>()
+                .iter()
+                .map(|value| value.map(|value| self.corrupt_number(topic, field.name(), value, dt, rng)))
+                .collect();
+            columns.push(Arc::new(corrupted) as ArrayRef);
+        }
+        RecordBatch::try_new(schema, columns)
+    }
+
+    /// Apply the delivery faults to a sample, returning what should be
+    /// published now, in order.
+    pub fn deliver(&mut self, topic: &str, time: u64, payload: Payload, rng: &mut StdRng) -> Vec<Sample> {
+        let mut ready = Vec::new();
+
+        let sample = (topic.to_string(), time, payload);
+        if rng.gen_bool(self.config.duplicate_probability) {
+            ready.push(sample.clone());
+        }
+
+        // Publish the incoming sample first so the held one arrives late
+        if rng.gen_bool(self.config.reorder_probability) && !self.held.contains_key(topic) {
+            self.held.insert(topic.to_string(), sample);
+        } else {
+            ready.push(sample);
+            ready.extend(self.held.remove(topic));
+        }
+
+        if self.burst_end.is_none() && rng.gen_bool(self.config.burst_probability) {
+            self.burst_end = Some(time + self.config.burst_duration_ms);
+        }
+        match self.burst_end {
+            Some(burst_end) if time < burst_end => {
+                self.delayed.extend(ready);
+                Vec::new()
+            }
+            Some(_) => {
+                self.burst_end = None;
+                let mut released = std::mem::take(&mut self.delayed);
+                released.extend(ready);
+                released
+            }
+            None => ready,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datapoint::DataPoint;
+    use rand::SeedableRng;
+    use serde_json::json;
+
+    const TRIALS: usize = 10_000;
+
+    fn injector(config: FaultConfig) -> (FaultInjector, StdRng) {
+        (FaultInjector::new(config), StdRng::seed_from_u64(7))
+    }
+
+    fn payload(time: u64) -> Payload {
+        Payload::Datapoint(DataPoint::with_json("robot".to_string(), time, "{}".to_string()))
+    }
+
+    /// Deliver a sample at each of `times`, returning the published times
+    /// after each one
+    fn deliver(faults: &mut FaultInjector, rng: &mut StdRng, times: impl IntoIterator<Item = u64>) -> Vec<Vec<u64>> {
+        times.into_iter()
+            .map(|time| {
+                faults.deliver("robot", time, payload(time), rng)
+                    .into_iter()
+                    .map(|(topic, time, _)| {
+                        assert_eq!(topic, "robot");
+                        time
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn assert_rate(count: usize, probability: f64) {
+        let rate = count as f64 / TRIALS as f64;
+        assert!((rate - probability).abs() < 0.02, "rate {} for probability {}", rate, probability);
+    }
+
+    #[test]
+    fn clean_config_changes_nothing() {
+        let (mut faults, mut rng) = injector(FaultConfig::default());
+        let mut value = json!({ "x": 1.5, "n": 2, "nested": [0.25] });
+        faults.corrupt_value("robot", &mut value, 0.01, &mut rng);
+        assert_eq!(value, json!({ "x": 1.5, "n": 2, "nested": [0.25] }));
+        assert!(!faults.should_drop(&mut rng));
+        assert_eq!(deliver(&mut faults, &mut rng, 0..3), [[0], [1], [2]]);
+    }
+
+    #[test]
+    fn drops_happen_at_their_probability() {
+        let (faults, mut rng) = injector(FaultConfig { drop_probability: 0.3, ..Default::default() });
+        assert_rate((0..TRIALS).filter(|_| faults.should_drop(&mut rng)).count(), 0.3);
+    }
+
+    #[test]
+    fn duplicates_repeat_the_timestamp() {
+        let (mut faults, mut rng) = injector(FaultConfig { duplicate_probability: 1.0, ..Default::default() });
+        assert_eq!(deliver(&mut faults, &mut rng, [5, 6]), [[5, 5], [6, 6]]);
+
+        let (mut faults, mut rng) = injector(FaultConfig { duplicate_probability: 0.2, ..Default::default() });
+        let published = deliver(&mut faults, &mut rng, 0..TRIALS as u64);
+        assert_rate(published.iter().filter(|times| times.len() == 2).count(), 0.2);
+    }
+
+    #[test]
+    fn reordered_samples_arrive_after_the_next() {
+        let (mut faults, mut rng) = injector(FaultConfig { reorder_probability: 1.0, ..Default::default() });
+        let published = deliver(&mut faults, &mut rng, 0..6);
+        assert_eq!(published, [vec![], vec![1, 0], vec![], vec![3, 2], vec![], vec![5, 4]]);
+
+        // Every sample arrives once and at most one place late, only the
+        // newest can still be held
+        let (mut faults, mut rng) = injector(FaultConfig { reorder_probability: 0.3, ..Default::default() });
+        let published: Vec<u64> = deliver(&mut faults, &mut rng, 0..TRIALS as u64).concat();
+        assert!(published.len() >= TRIALS - 1);
+        let mut sorted = published.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..published.len() as u64).collect::<Vec<_>>());
+        assert!(published.iter().enumerate().all(|(index, time)| time.abs_diff(index as u64) <= 1));
+        let swapped = published.windows(2).filter(|pair| pair[0] > pair[1]).count();
+        assert!(swapped > TRIALS / 5, "only {} swaps", swapped);
+    }
+
+    #[test]
+    fn bursts_hold_samples_then_release_them_in_order() {
+        let (mut faults, mut rng) = injector(FaultConfig {
+            burst_probability: 1.0,
+            burst_duration_ms: 50,
+            ..Default::default()
+        });
+        let published = deliver(&mut faults, &mut rng, (0..8).map(|index| index * 10));
+        let mut expected = vec![vec![]; 8];
+        expected[5] = vec![0, 10, 20, 30, 40, 50];
+        assert_eq!(published, expected);
+    }
+
+    #[test]
+    fn spikes_are_large_and_finite() {
+        let (mut faults, mut rng) = injector(FaultConfig { spike_probability: 1.0, ..Default::default() });
+        let mut signs = [0, 0];
+        for value in [0.0_f64, 0.5, -2.0, 1e300] {
+            for _ in 0..100 {
+                let mut data = json!({ "x": value });
+                faults.corrupt_value("robot", &mut data, 0.01, &mut rng);
+                let spike = data["x"].as_f64().expect("spikes stay numbers");
+                assert!(spike.is_finite());
+                assert_eq!(spike.abs(), SPIKE_GAIN * value.abs().clamp(1.0, f64::MAX / SPIKE_GAIN));
+                signs[(spike > 0.0) as usize] += 1;
+            }
+        }
+        assert!(signs[0] > 100 && signs[1] > 100, "signs {:?}", signs);
+
+        let batch = RecordBatch::try_from_iter([
+            ("x", Arc::new(Float64Array::from(vec![1.5; 100])) as ArrayRef),
+            ("n", Arc::new(arrow::array::Int64Array::from(vec![2; 100])) as ArrayRef),
+        ]).unwrap();
+        let corrupted = faults.corrupt_batch("robot", batch, 0.01, &mut rng).unwrap();
+        assert!(corrupted.column(0).as_primitive::<Float64Type>().values().iter().all(|x| x.abs() == 1500.0));
+        assert_eq!(corrupted.column(1), &(Arc::new(arrow::array::Int64Array::from(vec![2; 100])) as ArrayRef));
+    }
+
+    #[test]
+    fn noise_and_drift_have_their_standard_deviation() {
+        fn std(values: &[f64]) -> (f64, f64) {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / values.len() as f64;
+            (mean, variance.sqrt())
+        }
+
+        let (mut faults, mut rng) = injector(FaultConfig { noise_std: 0.5, ..Default::default() });
+        let noisy: Vec<f64> = (0..TRIALS).map(|_| faults.corrupt_number("robot", "x", 3.0, 0.01, &mut rng)).collect();
+        let (mean, noise_std) = std(&noisy);
+        assert!((mean - 3.0).abs() < 0.02 && (noise_std - 0.5).abs() < 0.02, "mean {}, std {}", mean, noise_std);
+
+        // The bias walks by `bias_drift_std * √dt` per sample, per column
+        let (mut faults, mut rng) = injector(FaultConfig { bias_drift_std: 2.0, ..Default::default() });
+        let drifted: Vec<f64> = (0..TRIALS).map(|_| faults.corrupt_number("robot", "x", 0.0, 0.25, &mut rng)).collect();
+        let steps: Vec<f64> = drifted.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let (_, step_std) = std(&steps);
+        assert!((step_std - 1.0).abs() < 0.03, "step std {}", step_std);
+        assert_eq!(faults.corrupt_number("robot", "y", 0.0, 0.0, &mut rng), 0.0);
+    }
+}
@@ -0,0 +1,269 @@
+//! Conversion between nested Arrow structs and the flat, dot-separated
+//! columns (`pose.position.x`) used on the wire.
+
+use arrow::array::{make_array, Array, ArrayRef, AsArray, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, Schema};
+use arrow::error::ArrowError;
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use std::sync::Arc;
+
+enum Node {
+    Leaf(FieldRef, ArrayRef),
+    Branch(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn insert(&mut self, path: &[&str], field: FieldRef, array: ArrayRef) -> Result<(), ArrowError> {
+        let Node::Branch(children) = self else {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Column {} is nested under a leaf column",
+                field.name()
+            )));
+        };
+
+        let (name, rest) = path.split_first().expect("paths are never empty");
+        let index = match children.iter().position(|(child, _)| child == name) {
+            Some(index) => index,
+            None => {
+                children.push((name.to_string(), Node::Branch(Vec::new())));
+                children.len() - 1
+            }
+        };
+
+        let child = &mut children[index].1;
+        if rest.is_empty() {
+            if !matches!(child, Node::Branch(grandchildren) if grandchildren.is_empty()) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Column {} collides with another column",
+                    field.name()
+                )));
+            }
+            *child = Node::Leaf(field, array);
+            Ok(())
+        } else {
+            child.insert(rest, field, array)
+        }
+    }
+
+    fn into_array(self, name: &str, len: usize) -> (FieldRef, ArrayRef) {
+        match self {
+            Node::Leaf(field, array) => (Arc::new(field.as_ref().clone().with_name(name)), array),
+            Node::Branch(children) => {
+                let (fields, arrays): (Vec<FieldRef>, Vec<ArrayRef>) = children
+                    .into_iter()
+                    .map(|(child_name, child)| child.into_array(&child_name, len))
+                    .unzip();
+                let array = struct_array(fields, arrays, len);
+                let field = Arc::new(Field::new(name, array.data_type().clone(), false));
+                (field, Arc::new(array))
+            }
+        }
+    }
+}
+
+fn struct_array(fields: Vec<FieldRef>, arrays: Vec<ArrayRef>, len: usize) -> StructArray {
+    if fields.is_empty() {
+        return StructArray::new_empty_fields(len, None);
+    }
+    StructArray::new(Fields::from(fields), arrays, None)
+}
+
+/// Rebuild nested structs from dot-separated columns.
+///
+/// Columns keep their first-seen order, so `pose.position.x, pose.position.y,
+/// velocity.x` becomes `{pose: {position: {x, y}}, velocity: {x}}`.
+pub fn unflatten_columns(
+    columns: impl IntoIterator<Item = (FieldRef, ArrayRef)>,
+    len: usize,
+) -> Result<StructArray, ArrowError> {
+    let mut root = Node::Branch(Vec::new());
+    for (field, array) in columns {
+        if array.len() != len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Column {} has {} rows, expected {}",
+                field.name(),
+                array.len(),
+                len
+            )));
+        }
+        let name = field.name().clone();
+        let path: Vec<&str> = name.split('.').collect();
+        root.insert(&path, field, array)?;
+    }
+
+    let Node::Branch(children) = root else {
+        unreachable!("the root is always a branch");
+    };
+    let (fields, arrays) = children
+        .into_iter()
+        .map(|(name, child)| child.into_array(&name, len))
+        .unzip();
+    Ok(struct_array(fields, arrays, len))
+}
+
+/// Flatten nested structs into leaf columns named by their dotted path.
+///
+/// The inverse of [`unflatten_columns`]. A null struct row makes every leaf
+/// under it null for that row.
+pub fn flatten_struct(array: &StructArray) -> Vec<(FieldRef, ArrayRef)> {
+    let mut columns = Vec::new();
+    flatten_into(&mut columns, "", array, None);
+    columns
+}
+
+/// Expand every struct column of a batch into dotted leaf columns, leaving
+/// flat batches untouched.
+pub fn flatten_batch(batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+    let has_structs = batch.schema().fields().iter()
+        .any(|field| matches!(field.data_type(), DataType::Struct(_)));
+    if !has_structs {
+        return Ok(batch.clone());
+    }
+
+    let (fields, columns): (Vec<FieldRef>, Vec<ArrayRef>) = flatten_struct(&StructArray::from(batch.clone()))
+        .into_iter()
+        .unzip();
+    let schema = Schema::new(fields).with_metadata(batch.schema().metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+}
+
+fn flatten_into(
+    columns: &mut Vec<(FieldRef, ArrayRef)>,
+    prefix: &str,
+    array: &StructArray,
+    parent_nulls: Option<&NullBuffer>,
+) {
+    let nulls = NullBuffer::union(parent_nulls, array.nulls());
+
+    for (field, column) in array.fields().iter().zip(array.columns()) {
+        let name = format!("{}{}", prefix, field.name());
+        match field.data_type() {
+            DataType::Struct(_) => {
+                flatten_into(columns, &format!("{}.", name), column.as_struct(), nulls.as_ref());
+            }
+            _ => {
+                let column = with_parent_nulls(column, nulls.as_ref());
+                let nullable = field.is_nullable() || column.null_count() > 0;
+                let field = Field::new(name, field.data_type().clone(), nullable)
+                    .with_metadata(field.metadata().clone());
+                columns.push((Arc::new(field), column));
+            }
+        }
+    }
+}
+
+fn with_parent_nulls(column: &ArrayRef, parent_nulls: Option<&NullBuffer>) -> ArrayRef {
+    let Some(parent_nulls) = parent_nulls.filter(|nulls| nulls.null_count() > 0) else {
+        return Arc::clone(column);
+    };
+    // Null arrays carry no validity buffer, every row is already null
+    if column.data_type() == &DataType::Null {
+        return Arc::clone(column);
+    }
+
+    let nulls = NullBuffer::union(Some(parent_nulls), column.nulls());
+    let data = column.to_data().into_builder().nulls(nulls).build()
+        .expect("only the validity buffer changed");
+    make_array(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float64Array, Int64Array, StringArray};
+
+    fn leaf(name: &str, array: ArrayRef) -> (FieldRef, ArrayRef) {
+        (Arc::new(Field::new(name, array.data_type().clone(), false)), array)
+    }
+
+    fn floats(values: &[f64]) -> ArrayRef {
+        Arc::new(Float64Array::from(values.to_vec()))
+    }
+
+    fn names(columns: &[(FieldRef, ArrayRef)]) -> Vec<&str> {
+        columns.iter().map(|(field, _)| field.name().as_str()).collect()
+    }
+
+    fn pose() -> Vec<(FieldRef, ArrayRef)> {
+        vec![
+            leaf("pose.position.x", floats(&[1.0, 2.0])),
+            leaf("pose.position.y", floats(&[3.0, 4.0])),
+            leaf("label", Arc::new(StringArray::from(vec!["a", "b"]))),
+            leaf("pose.heading", Arc::new(Int64Array::from(vec![90, 180]))),
+        ]
+    }
+
+    #[test]
+    fn unflatten_nests_by_dotted_path_in_first_seen_order() {
+        let array = unflatten_columns(pose(), 2).unwrap();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.fields().iter().map(|field| field.name().as_str()).collect::<Vec<_>>(), ["pose", "label"]);
+
+        let pose = array.column(0).as_struct();
+        assert_eq!(pose.fields().iter().map(|field| field.name().as_str()).collect::<Vec<_>>(), ["position", "heading"]);
+        let position = pose.column(0).as_struct();
+        assert_eq!(position.column(1), &floats(&[3.0, 4.0]));
+    }
+
+    #[test]
+    fn flatten_inverts_unflatten() {
+        let columns = flatten_struct(&unflatten_columns(pose(), 2).unwrap());
+        // Leaves come back grouped under their parent
+        assert_eq!(names(&columns), ["pose.position.x", "pose.position.y", "pose.heading", "label"]);
+        for (field, array) in &columns {
+            let (_, expected) = pose().into_iter().find(|(expected, _)| expected.name() == field.name()).unwrap();
+            assert_eq!(array, &expected);
+        }
+    }
+
+    #[test]
+    fn unflatten_rejects_collisions_and_wrong_lengths() {
+        let leaf_then_child = vec![leaf("a", floats(&[1.0])), leaf("a.b", floats(&[2.0]))];
+        assert!(unflatten_columns(leaf_then_child, 1).is_err());
+
+        let child_then_leaf = vec![leaf("a.b", floats(&[1.0])), leaf("a", floats(&[2.0]))];
+        assert!(unflatten_columns(child_then_leaf, 1).is_err());
+
+        let duplicate = vec![leaf("a", floats(&[1.0])), leaf("a", floats(&[2.0]))];
+        assert!(unflatten_columns(duplicate, 1).is_err());
+
+        assert!(unflatten_columns(vec![leaf("a", floats(&[1.0, 2.0]))], 3).is_err());
+    }
+
+    #[test]
+    fn null_parent_rows_null_their_leaves() {
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("x", DataType::Float64, false)),
+            floats(&[1.0, 2.0, 3.0]),
+        )]);
+        let inner = StructArray::new(
+            inner.fields().clone(),
+            inner.columns().to_vec(),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let outer = StructArray::from(vec![(
+            Arc::new(Field::new("pose", inner.data_type().clone(), true)),
+            Arc::new(inner) as ArrayRef,
+        )]);
+
+        let columns = flatten_struct(&outer);
+        assert_eq!(names(&columns), ["pose.x"]);
+        let (field, array) = &columns[0];
+        assert!(field.is_nullable());
+        assert_eq!(array, &(Arc::new(Float64Array::from(vec![Some(1.0), None, Some(3.0)])) as ArrayRef));
+    }
+
+    #[test]
+    fn flatten_batch_keeps_flat_batches_and_row_count() {
+        let flat = RecordBatch::try_from_iter(vec![("x", floats(&[1.0]))]).unwrap();
+        assert_eq!(flatten_batch(&flat).unwrap(), flat);
+
+        let nested = unflatten_columns(pose(), 2).unwrap();
+        let batch = flatten_batch(&RecordBatch::from(nested)).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().fields().iter().map(|field| field.name().as_str()).collect::<Vec<_>>(),
+            ["pose.position.x", "pose.position.y", "pose.heading", "label"]);
+    }
+}
@@ -0,0 +1,490 @@
+//! [Foxglove WebSocket protocol](https://github.com/foxglove/ws-protocol)
+//! endpoint, served on `/foxglove` so Foxglove can connect to the bridge.
+//!
+//! Each connection follows the same bus as `/ws` connections. Every topic is
+//! advertised as a `json` channel with a `jsonschema` schema as it first
+//! appears, or from history when the connection opens, and advertised again
+//! under a new channel id if its schema changes. Subscribed channels receive
+//! one `messageData` frame per row, stamped with the row's time. The server
+//! declares the `time` capability and sends the newest data time, or the
+//! replay position, as `time` frames so Foxglove's clock follows the data.
+
+use crate::arrow_serde::json_schema;
+use crate::datapoint::{self, DataPoint};
+use crate::state::{BusMessage, Payload, StateHandle};
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use futures::{SinkExt, StreamExt};
+use log::{debug, error, info, warn};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::MissedTickBehavior;
+use warp::ws::{Message, WebSocket};
+use warp::{Rejection, Reply};
+
+/// Negotiated with the client, Foxglove refuses servers that don't echo it
+pub const SUBPROTOCOL: &str = "foxglove.websocket.v1";
+
+const OP_MESSAGE_DATA: u8 = 0x01;
+const OP_TIME: u8 = 0x02;
+
+const NANOS_PER_MS: u64 = 1_000_000;
+
+/// How often the clock is sent while the data time moves
+const TIME_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(serde::Serialize, Debug)]
+#[serde(tag = "op", rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum ServerMessage {
+    ServerInfo {
+        name: String,
+        capabilities: Vec<String>,
+        supported_encodings: Vec<String>,
+        metadata: BTreeMap<String, String>,
+        session_id: String,
+    },
+    Advertise {
+        channels: Vec<Channel>,
+    },
+    Unadvertise {
+        channel_ids: Vec<u32>,
+    },
+    Status {
+        /// `0` info, `1` warning, `2` error
+        level: u8,
+        message: String,
+    },
+}
+
+impl ServerMessage {
+    fn to_message(&self) -> Message {
+        Message::text(serde_json::to_string(self).unwrap())
+    }
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Channel {
+    id: u32,
+    topic: String,
+    encoding: String,
+    schema_name: String,
+    schema: String,
+    schema_encoding: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(tag = "op", rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum ClientMessage {
+    Subscribe {
+        subscriptions: Vec<Subscription>,
+    },
+    Unsubscribe {
+        subscription_ids: Vec<u32>,
+    },
+    /// Publishing, parameters, services and the like aren't offered
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Subscription {
+    id: u32,
+    channel_id: u32,
+}
+
+/// A topic advertised on this connection
+struct TopicChannel {
+    id: u32,
+    /// Schema of the batches the JSON schema was derived from
+    arrow_schema: SchemaRef,
+    schema: String,
+}
+
+/// Channels, subscriptions and clock of one connection
+#[derive(Default)]
+struct Session {
+    channels: HashMap<String, TopicChannel>,
+    next_channel_id: u32,
+    /// Channel per subscription id, chosen by the client
+    subscriptions: HashMap<u32, u32>,
+    latest_time: Option<u64>,
+    sent_time: Option<u64>,
+}
+
+/// A data payload as flattened `topic`, `time` and data columns
+fn payload_batch(payload: &Payload) -> Option<Result<RecordBatch, ArrowError>> {
+    match payload {
+        Payload::Datapoint(datapoint) => Some(datapoint.to_flattened_record_batch()),
+        Payload::Batch(batch) => Some(Ok(batch.clone())),
+        Payload::Status(_) => None,
+    }
+}
+
+fn message_data(subscription_id: u32, time: u64, data: &[u8]) -> Message {
+    let mut frame = Vec::with_capacity(13 + data.len());
+    frame.push(OP_MESSAGE_DATA);
+    frame.extend_from_slice(&subscription_id.to_le_bytes());
+    frame.extend_from_slice(&time.saturating_mul(NANOS_PER_MS).to_le_bytes());
+    frame.extend_from_slice(data);
+    Message::binary(frame)
+}
+
+impl Session {
+    /// Advertise `topic` if it is new or its schema changed, returning the
+    /// `unadvertise` and `advertise` messages to send
+    fn advertise(&mut self, topic: &str, batch: &RecordBatch) -> Result<Vec<ServerMessage>, ArrowError> {
+        if self.channels.get(topic).is_some_and(|channel| channel.arrow_schema == batch.schema()) {
+            return Ok(Vec::new());
+        }
+
+        let schema = json_schema(&datapoint::batch_data_type(batch)?).to_string();
+
+        let mut messages = Vec::new();
+        if let Some(channel) = self.channels.get_mut(topic) {
+            // Batches can differ in ways the JSON doesn't show, such as nullability
+            if channel.schema == schema {
+                channel.arrow_schema = batch.schema();
+                return Ok(messages);
+            }
+            let old_id = channel.id;
+            self.subscriptions.retain(|_, channel_id| *channel_id != old_id);
+            messages.push(ServerMessage::Unadvertise { channel_ids: vec![old_id] });
+        }
+
+        self.next_channel_id += 1;
+        let id = self.next_channel_id;
+        messages.push(ServerMessage::Advertise {
+            channels: vec![Channel {
+                id,
+                topic: topic.to_string(),
+                encoding: "json".to_string(),
+                schema_name: topic.to_string(),
+                schema: schema.clone(),
+                schema_encoding: "jsonschema".to_string(),
+            }],
+        });
+        self.channels.insert(topic.to_string(), TopicChannel { id, arrow_schema: batch.schema(), schema });
+        Ok(messages)
+    }
+
+    /// Frames for a message from the bus
+    fn bus_message(&mut self, bus_message: &BusMessage) -> Result<Vec<Message>, ArrowError> {
+        let batch = match &bus_message.payload {
+            Payload::Datapoint(datapoint) => datapoint.to_flattened_record_batch()?,
+            Payload::Batch(batch) => batch.clone(),
+            Payload::Status(status) => {
+                self.latest_time = Some(status.time);
+                return Ok(Vec::new());
+            }
+        };
+
+        let mut frames: Vec<Message> = self.advertise(&bus_message.topic, &batch)?
+            .iter()
+            .map(ServerMessage::to_message)
+            .collect();
+        if let Some((_, end)) = datapoint::batch_time_range(&batch)? {
+            self.latest_time = Some(end);
+        }
+
+        let channel_id = self.channels[&bus_message.topic].id;
+        let subscription_ids: Vec<u32> = self.subscriptions.iter()
+            .filter(|(_, subscribed)| **subscribed == channel_id)
+            .map(|(subscription_id, _)| *subscription_id)
+            .collect();
+        if subscription_ids.is_empty() {
+            return Ok(frames);
+        }
+
+        for datapoint in DataPoint::from_record_batch(&batch)? {
+            let data: Value = datapoint.to_data()?;
+            let data = data.to_string().into_bytes();
+            for subscription_id in &subscription_ids {
+                frames.push(message_data(*subscription_id, datapoint.time, &data));
+            }
+        }
+        Ok(frames)
+    }
+
+    /// Handle a text frame from the client, returning any status to report
+    fn client_message(&mut self, text: &str) -> Vec<Message> {
+        let message: ClientMessage = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(e) => {
+                let status = ServerMessage::Status { level: 2, message: format!("Bad message: {}", e) };
+                return vec![status.to_message()];
+            }
+        };
+
+        let mut statuses = Vec::new();
+        match message {
+            ClientMessage::Subscribe { subscriptions } => {
+                for subscription in subscriptions {
+                    if self.channels.values().any(|channel| channel.id == subscription.channel_id) {
+                        self.subscriptions.insert(subscription.id, subscription.channel_id);
+                    } else {
+                        statuses.push(ServerMessage::Status {
+                            level: 1,
+                            message: format!("Unknown channel {}", subscription.channel_id),
+                        });
+                    }
+                }
+            }
+            ClientMessage::Unsubscribe { subscription_ids } => {
+                for subscription_id in subscription_ids {
+                    self.subscriptions.remove(&subscription_id);
+                }
+            }
+            ClientMessage::Unsupported => debug!("Ignoring unsupported Foxglove message {}", text),
+        }
+        statuses.iter().map(ServerMessage::to_message).collect()
+    }
+
+    /// A `time` frame when the data time moved since the last one
+    fn time_frame(&mut self) -> Option<Message> {
+        let time = self.latest_time?;
+        if self.sent_time == Some(time) {
+            return None;
+        }
+        self.sent_time = Some(time);
+
+        let mut frame = Vec::with_capacity(9);
+        frame.push(OP_TIME);
+        frame.extend_from_slice(&time.saturating_mul(NANOS_PER_MS).to_le_bytes());
+        Some(Message::binary(frame))
+    }
+}
+
+pub async fn foxglove_handler(ws: warp::ws::Ws, state: StateHandle) -> Result<impl Reply, Rejection> {
+    let reply = ws.on_upgrade(|socket| async {
+        foxglove_connect(socket, state).await;
+    });
+    Ok(warp::reply::with_header(reply, "sec-websocket-protocol", SUBPROTOCOL))
+}
+
+pub async fn foxglove_connect(ws: WebSocket, state: StateHandle) {
+    let (mut client_ws_sender, mut client_ws_rcv) = ws.split();
+
+    let (client_id, mut bus_rx, latest, playback_status) = {
+        let mut state = state.lock().unwrap();
+        (state.next_client_id(), state.subscribe(), state.history.latest(), state.playback_status.clone())
+    };
+    info!("New Foxglove connection {}", client_id);
+
+    let mut session = Session {
+        latest_time: playback_status.map(|status| status.time),
+        ..Default::default()
+    };
+
+    let server_info = ServerMessage::ServerInfo {
+        name: concat!("cursed-ws-bridge ", env!("CARGO_PKG_VERSION")).to_string(),
+        capabilities: vec!["time".to_string()],
+        supported_encodings: Vec::new(),
+        metadata: BTreeMap::new(),
+        session_id: client_id.to_string(),
+    };
+    let mut frames = vec![server_info.to_message()];
+
+    // Topics published before the client connected, in one advertise
+    let mut channels = Vec::new();
+    for bus_message in &latest {
+        let Some(Ok(batch)) = payload_batch(&bus_message.payload) else {
+            continue;
+        };
+        match session.advertise(&bus_message.topic, &batch) {
+            Ok(messages) => channels.extend(messages.into_iter().flat_map(|message| match message {
+                ServerMessage::Advertise { channels } => channels,
+                _ => Vec::new(),
+            })),
+            Err(e) => warn!("Not advertising {}: {}", bus_message.topic, e),
+        }
+    }
+    if !channels.is_empty() {
+        frames.push(ServerMessage::Advertise { channels }.to_message());
+    }
+
+    let mut time_timer = tokio::time::interval(TIME_INTERVAL);
+    time_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    'connection: loop {
+        for frame in frames.drain(..) {
+            if let Err(e) = client_ws_sender.send(frame).await {
+                error!("Error sending to Foxglove client {}: {}", client_id, e);
+                break 'connection;
+            }
+        }
+
+        tokio::select! {
+            received = bus_rx.recv() => match received {
+                Ok(bus_message) => match session.bus_message(&bus_message) {
+                    Ok(bus_frames) => frames = bus_frames,
+                    Err(e) => warn!("Not sending {} to Foxglove client {}: {}", bus_message.topic, client_id, e),
+                },
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Foxglove client {} lagged, skipped {} messages", client_id, skipped);
+                }
+                Err(RecvError::Closed) => break,
+            },
+            received = client_ws_rcv.next() => match received {
+                Some(Ok(msg)) if msg.is_close() => break,
+                Some(Ok(msg)) => {
+                    if let Ok(text) = msg.to_str() {
+                        frames = session.client_message(text);
+                    }
+                }
+                Some(Err(e)) => {
+                    error!("WebSocket error on Foxglove client {}: {}", client_id, e);
+                    break;
+                }
+                None => break,
+            },
+            _ = time_timer.tick() => frames.extend(session.time_frame()),
+        }
+    }
+
+    info!("Foxglove connection {} closed", client_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow_serde::Shape;
+    use crate::ws_handler::arrow_batch;
+    use arrow::datatypes::{Field, Schema};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn batch(time: u64, data: Value) -> RecordBatch {
+        arrow_batch("robot", time, &data, &Shape::Infer).unwrap()
+    }
+
+    fn bus_message(batch: RecordBatch) -> BusMessage {
+        BusMessage { source: None, topic: "robot".to_string(), payload: Payload::Batch(batch) }
+    }
+
+    fn json_frames(frames: &[Message]) -> Vec<Value> {
+        frames.iter().map(|frame| serde_json::from_str(frame.to_str().unwrap()).unwrap()).collect()
+    }
+
+    /// The same batch with every column nullable
+    fn nullable(batch: &RecordBatch) -> RecordBatch {
+        let fields: Vec<Field> = batch.schema().fields().iter()
+            .map(|field| field.as_ref().clone().with_nullable(true))
+            .collect();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), batch.columns().to_vec()).unwrap()
+    }
+
+    #[test]
+    fn advertises_new_topics_once() {
+        let mut session = Session::default();
+        let messages = session.advertise("robot", &batch(1, json!({ "x": 1.5 }))).unwrap();
+        let [ServerMessage::Advertise { channels }] = messages.as_slice() else {
+            panic!("expected one advertise, got {:?}", messages);
+        };
+        assert_eq!(channels[0].id, 1);
+        assert_eq!(channels[0].topic, "robot");
+        assert_eq!(channels[0].encoding, "json");
+        assert_eq!(channels[0].schema_encoding, "jsonschema");
+        let schema: Value = serde_json::from_str(&channels[0].schema).unwrap();
+        assert_eq!(schema["properties"]["x"], json!({ "type": "number" }));
+
+        assert!(session.advertise("robot", &batch(2, json!({ "x": 2.5 }))).unwrap().is_empty());
+    }
+
+    #[test]
+    fn schema_changes_readvertise_under_a_new_id() {
+        let mut session = Session::default();
+        session.advertise("robot", &batch(1, json!({ "x": 1.5 }))).unwrap();
+        session.client_message(r#"{"op":"subscribe","subscriptions":[{"id":7,"channelId":1}]}"#);
+
+        let messages = session.advertise("robot", &batch(2, json!({ "x": "fast" }))).unwrap();
+        let [ServerMessage::Unadvertise { channel_ids }, ServerMessage::Advertise { channels }] = messages.as_slice() else {
+            panic!("expected unadvertise then advertise, got {:?}", messages);
+        };
+        assert_eq!(channel_ids, &[1]);
+        assert_eq!(channels[0].id, 2);
+        // Subscriptions to the old channel are gone
+        assert!(session.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn nullability_changes_stay_silent() {
+        let mut session = Session::default();
+        let first = batch(1, json!({ "x": 1.5 }));
+        session.advertise("robot", &first).unwrap();
+        let second = nullable(&batch(2, json!({ "x": 2.5 })));
+        assert_ne!(first.schema(), second.schema());
+
+        assert!(session.advertise("robot", &second).unwrap().is_empty());
+        assert_eq!(session.channels["robot"].id, 1);
+        assert_eq!(session.channels["robot"].arrow_schema, second.schema());
+    }
+
+    #[test]
+    fn subscribing_to_unknown_channels_is_rejected() {
+        let mut session = Session::default();
+        session.advertise("robot", &batch(1, json!({ "x": 1.5 }))).unwrap();
+
+        let statuses = json_frames(&session.client_message(
+            r#"{"op":"subscribe","subscriptions":[{"id":1,"channelId":1},{"id":2,"channelId":9}]}"#
+        ));
+        assert_eq!(statuses, [json!({ "op": "status", "level": 1, "message": "Unknown channel 9" })]);
+        assert_eq!(session.subscriptions, HashMap::from([(1, 1)]));
+
+        assert!(session.client_message(r#"{"op":"unsubscribe","subscriptionIds":[1]}"#).is_empty());
+        assert!(session.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn bad_and_unsupported_client_messages() {
+        let mut session = Session::default();
+        let statuses = json_frames(&session.client_message("not json"));
+        assert_eq!(statuses[0]["level"], 2);
+        assert!(session.client_message(r#"{"op":"getParameters","parameterNames":[]}"#).is_empty());
+    }
+
+    #[test]
+    fn message_data_layout() {
+        let frame = message_data(0x0403_0201, 1500, b"{}");
+        let mut expected = vec![OP_MESSAGE_DATA, 0x01, 0x02, 0x03, 0x04];
+        expected.extend_from_slice(&1_500_000_000u64.to_le_bytes());
+        expected.extend_from_slice(b"{}");
+        assert_eq!(frame.as_bytes(), expected);
+    }
+
+    #[test]
+    fn subscribed_rows_become_message_data() {
+        let mut session = Session::default();
+        session.bus_message(&bus_message(batch(1000, json!({ "x": 1.5 })))).unwrap();
+        session.client_message(r#"{"op":"subscribe","subscriptions":[{"id":5,"channelId":1}]}"#);
+
+        let frames = session.bus_message(&bus_message(batch(1010, json!({ "x": 2.5 })))).unwrap();
+        assert_eq!(frames.len(), 1);
+        let bytes = frames[0].as_bytes();
+        assert_eq!(bytes[0], OP_MESSAGE_DATA);
+        assert_eq!(u32::from_le_bytes(bytes[1..5].try_into().unwrap()), 5);
+        assert_eq!(u64::from_le_bytes(bytes[5..13].try_into().unwrap()), 1_010_000_000);
+        assert_eq!(serde_json::from_slice::<Value>(&bytes[13..]).unwrap(), json!({ "x": 2.5 }));
+    }
+
+    #[test]
+    fn time_frames_follow_the_data_time() {
+        let mut session = Session::default();
+        assert!(session.time_frame().is_none());
+
+        session.bus_message(&bus_message(batch(2000, json!({ "x": 1.5 })))).unwrap();
+        let frame = session.time_frame().unwrap();
+        let mut expected = vec![OP_TIME];
+        expected.extend_from_slice(&2_000_000_000u64.to_le_bytes());
+        assert_eq!(frame.as_bytes(), expected);
+
+        // Only sent again once the time moves
+        assert!(session.time_frame().is_none());
+        session.latest_time = Some(u64::MAX);
+        assert_eq!(session.time_frame().unwrap().as_bytes()[1..], u64::MAX.to_le_bytes());
+    }
+}
@@ -0,0 +1,694 @@
+//! Synthetic data published by the bridge itself.
+//!
+//! A TOML config lists the topics to generate, each with its own rate and
+//! either a set of signal fields or a built-in struct type:
+//!
+//! ```toml
+//! seed = 42
+//!
+//! [[topics]]
+//! topic = "signals/wave"
+//! rate_hz = 100
+//! kind = "signals"
+//! fields = [
+//!     { name = "sine", type = "sine", frequency_hz = 0.5 },
+//!     { name = "mode", type = "state", states = ["idle", "drive"], dwell_s = 2.0 },
+//! ]
+//!
+//! [[topics]]
+//! topic = "test/topic"
+//! rate_hz = 1000
+//! kind = "test_data"
+//!
+//! [[topics]]
+//! topic = "vehicle"
+//! rate_hz = 100
+//! kind = "vehicle"
+//! cruise_speed = 5.0
+//! ```
+//!
+//! Without a config the bridge generates `TestData` on `test/topic` at
+//! `--send-rate-hz`, following the default simulated vehicle.
+
+use crate::arrow_serde::Shape;
+use crate::faults::{FaultConfig, FaultInjector};
+use crate::state::{Payload, StateHandle};
+use crate::vehicle::{VehicleParams, VehicleSim};
+use crate::ws_handler::{arrow_batch, SyncUpdate};
+use log::{error, info};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::f64::consts::TAU;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::time::MissedTickBehavior;
+
+/// Fastest rate a topic can be generated at, timestamps are in ms
+pub const MAX_RATE_HZ: f64 = 1000.0;
+
+fn default_one() -> f64 {
+    1.0
+}
+
+fn default_duty() -> f64 {
+    0.5
+}
+
+/// A single generated value, selected by `type` in the config
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Signal {
+    Sine {
+        #[serde(default = "default_one")]
+        amplitude: f64,
+        #[serde(default = "default_one")]
+        frequency_hz: f64,
+        /// Phase offset in radians
+        #[serde(default)]
+        phase: f64,
+        #[serde(default)]
+        offset: f64,
+    },
+    Square {
+        #[serde(default = "default_one")]
+        amplitude: f64,
+        #[serde(default = "default_one")]
+        frequency_hz: f64,
+        /// Fraction of each period spent high
+        #[serde(default = "default_duty")]
+        duty: f64,
+        #[serde(default)]
+        offset: f64,
+    },
+    Sawtooth {
+        #[serde(default = "default_one")]
+        amplitude: f64,
+        #[serde(default = "default_one")]
+        frequency_hz: f64,
+        #[serde(default)]
+        offset: f64,
+    },
+    /// Adds a normally distributed step every sample, clamped to `[min, max]`
+    RandomWalk {
+        #[serde(default)]
+        start: f64,
+        #[serde(default = "default_one")]
+        step_std: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Gaussian {
+        #[serde(default)]
+        mean: f64,
+        #[serde(default = "default_one")]
+        std: f64,
+    },
+    /// Linear sweep from `start_hz` to `end_hz`, restarting every `duration_s`
+    Chirp {
+        #[serde(default = "default_one")]
+        amplitude: f64,
+        start_hz: f64,
+        end_hz: f64,
+        duration_s: f64,
+    },
+    Step {
+        #[serde(default)]
+        before: f64,
+        #[serde(default = "default_one")]
+        after: f64,
+        at_s: f64,
+    },
+    /// String states held for `dwell_s` each, in order or picked at random
+    State {
+        states: Vec<String>,
+        dwell_s: f64,
+        #[serde(default)]
+        random: bool,
+    },
+}
+
+/// A named column of a generated topic
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct FieldConfig {
+    /// Column name, dots nest it like any other flattened column
+    pub name: String,
+    #[serde(flatten)]
+    pub signal: Signal,
+}
+
+/// What a topic publishes, selected by `kind` in the config
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TopicSource {
+    Signals { fields: Vec<FieldConfig> },
+    /// The simulated vehicle's state as `TestData`
+    TestData,
+    /// A simulated vehicle publishing `<topic>/pose`, `<topic>/twist`,
+    /// `<topic>/imu` and `<topic>/battery`
+    Vehicle {
+        #[serde(flatten)]
+        params: VehicleParams,
+    },
+    /// `agents` vehicles, each a random variation of `params`, publishing the
+    /// same topics as `Vehicle` under `<topic>_<index>/`
+    Fleet {
+        agents: usize,
+        /// Side of the square the agents start in, in m
+        #[serde(default = "default_spread_m")]
+        spread_m: f64,
+        /// Largest relative change applied to each agent's parameters
+        #[serde(default = "default_variation")]
+        variation: f64,
+        #[serde(flatten)]
+        params: VehicleParams,
+    },
+}
+
+fn default_spread_m() -> f64 {
+    50.0
+}
+
+fn default_variation() -> f64 {
+    0.3
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TopicConfig {
+    pub topic: String,
+    pub rate_hz: f64,
+    /// Impairments applied to everything this topic publishes
+    #[serde(default)]
+    pub faults: FaultConfig,
+    #[serde(flatten)]
+    pub source: TopicSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize, serde::Serialize)]
+pub struct GeneratorConfig {
+    /// Seed for the random signals, `None` seeds from the OS
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub topics: Vec<TopicConfig>,
+}
+
+impl GeneratorConfig {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parse and validate a TOML config
+    pub fn parse(text: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let config: Self = toml::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for topic in &self.topics {
+            if !topic.rate_hz.is_finite() || topic.rate_hz <= 0.0 {
+                return Err(format!("Topic {} needs a positive rate_hz", topic.topic));
+            }
+            // Timestamps are whole milliseconds, faster samples would share them
+            if topic.rate_hz > MAX_RATE_HZ {
+                return Err(format!("Topic {} needs a rate_hz of at most {}", topic.topic, MAX_RATE_HZ));
+            }
+            topic.faults.validate()
+                .map_err(|e| format!("Faults of {}: {}", topic.topic, e))?;
+            match &topic.source {
+                TopicSource::Signals { fields } => {
+                    for field in fields {
+                        let period = match &field.signal {
+                            Signal::Chirp { duration_s, .. } => *duration_s,
+                            Signal::State { dwell_s, .. } => *dwell_s,
+                            _ => 1.0,
+                        };
+                        if !period.is_finite() || period <= 0.0 {
+                            return Err(format!("Field {} of {} needs a positive duration", field.name, topic.topic));
+                        }
+                        if let Signal::Square { duty, .. } = &field.signal
+                            && !(0.0..=1.0).contains(duty)
+                        {
+                            return Err(format!("Field {} of {} needs a duty in [0, 1]", field.name, topic.topic));
+                        }
+                        if let Signal::State { states, .. } = &field.signal
+                            && states.is_empty()
+                        {
+                            return Err(format!("Field {} of {} needs at least one state", field.name, topic.topic));
+                        }
+                    }
+                }
+                TopicSource::Vehicle { params } => validate_vehicle(&topic.topic, params)?,
+                TopicSource::Fleet { agents, variation, params, .. } => {
+                    if *agents == 0 {
+                        return Err(format!("Fleet {} needs at least one agent", topic.topic));
+                    }
+                    if !(0.0..1.0).contains(variation) {
+                        return Err(format!("Fleet {} needs a variation in [0, 1)", topic.topic));
+                    }
+                    validate_vehicle(&topic.topic, params)?;
+                }
+                TopicSource::TestData => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// `TestData` on `test/topic`, what the bridge publishes without a config
+    pub fn test_data(rate_hz: f64) -> Self {
+        Self {
+            seed: None,
+            topics: vec![TopicConfig {
+                topic: "test/topic".to_string(),
+                rate_hz,
+                faults: FaultConfig::default(),
+                source: TopicSource::TestData,
+            }],
+        }
+    }
+}
+
+fn validate_vehicle(topic: &str, params: &VehicleParams) -> Result<(), String> {
+    let lengths = [
+        params.wheelbase,
+        params.speed_period_s,
+        params.steer_period_s,
+        params.battery.capacity_ah,
+    ];
+    if lengths.iter().any(|length| !length.is_finite() || *length <= 0.0) {
+        return Err(format!(
+            "Vehicle {} needs a positive wheelbase, speed_period_s, steer_period_s and battery.capacity_ah",
+            topic
+        ));
+    }
+    Ok(())
+}
+
+/// Running state of one signal between samples
+#[derive(Debug, Clone)]
+struct SignalState {
+    signal: Signal,
+    walk: f64,
+    state_index: usize,
+    state_slot: u64,
+}
+
+impl SignalState {
+    fn new(signal: Signal) -> Self {
+        let walk = match &signal {
+            Signal::RandomWalk { start, .. } => *start,
+            _ => 0.0,
+        };
+        Self {
+            signal,
+            walk,
+            state_index: 0,
+            state_slot: 0,
+        }
+    }
+
+    fn sample(&mut self, t: f64, rng: &mut StdRng) -> Value {
+        let value = match &self.signal {
+            Signal::Sine { amplitude, frequency_hz, phase, offset } => {
+                amplitude * (TAU * frequency_hz * t + phase).sin() + offset
+            }
+            Signal::Square { amplitude, frequency_hz, duty, offset } => {
+                let high = (frequency_hz * t).rem_euclid(1.0) < *duty;
+                if high { amplitude + offset } else { offset - amplitude }
+            }
+            Signal::Sawtooth { amplitude, frequency_hz, offset } => {
+                amplitude * (2.0 * (frequency_hz * t).rem_euclid(1.0) - 1.0) + offset
+            }
+            Signal::RandomWalk { step_std, min, max, .. } => {
+                let step: f64 = rng.sample(StandardNormal);
+                self.walk += step * step_std;
+                if let Some(min) = min {
+                    self.walk = self.walk.max(*min);
+                }
+                if let Some(max) = max {
+                    self.walk = self.walk.min(*max);
+                }
+                self.walk
+            }
+            Signal::Gaussian { mean, std } => {
+                let noise: f64 = rng.sample(StandardNormal);
+                mean + std * noise
+            }
+            Signal::Chirp { amplitude, start_hz, end_hz, duration_s } => {
+                let tau = t.rem_euclid(*duration_s);
+                let sweep = (end_hz - start_hz) / (2.0 * duration_s);
+                amplitude * (TAU * (start_hz * tau + sweep * tau * tau)).sin()
+            }
+            Signal::Step { before, after, at_s } => {
+                if t >= *at_s { *after } else { *before }
+            }
+            Signal::State { states, dwell_s, random } => {
+                if states.is_empty() {
+                    return Value::Null;
+                }
+                let slot = (t / dwell_s) as u64;
+                if slot != self.state_slot {
+                    self.state_slot = slot;
+                    self.state_index = if *random {
+                        rng.gen_range(0..states.len())
+                    } else {
+                        slot as usize % states.len()
+                    };
+                }
+                return Value::String(states[self.state_index].clone());
+            }
+        };
+        Value::from(value)
+    }
+
+    fn shape(&self) -> Shape {
+        match self.signal {
+            Signal::State { .. } => Shape::Utf8,
+            _ => Shape::Float64,
+        }
+    }
+}
+
+/// Produces the rows of one configured topic
+enum TopicGenerator {
+    /// The fields and the shape of the rows they make
+    Signals(Vec<(String, SignalState)>, Arc<Shape>),
+    TestData(Box<VehicleSim>),
+    /// Vehicles with the namespace their topics go under
+    Vehicles(Vec<(String, VehicleSim)>),
+}
+
+impl TopicGenerator {
+    fn new(topic: &str, source: &TopicSource, rng: &mut StdRng) -> Self {
+        match source {
+            TopicSource::Signals { fields } => {
+                let fields: Vec<(String, SignalState)> = fields
+                    .iter()
+                    .map(|field| (field.name.clone(), SignalState::new(field.signal.clone())))
+                    .collect();
+                let shape = Shape::Struct(fields.iter().map(|(name, signal)| (name.clone(), signal.shape())).collect());
+                TopicGenerator::Signals(fields, Arc::new(shape))
+            }
+            TopicSource::TestData => TopicGenerator::TestData(Box::new(VehicleSim::new(VehicleParams::default()))),
+            TopicSource::Vehicle { params } => {
+                TopicGenerator::Vehicles(vec![(topic.to_string(), VehicleSim::new(params.clone()))])
+            }
+            TopicSource::Fleet { agents, spread_m, variation, params } => TopicGenerator::Vehicles(
+                (0..*agents)
+                    .map(|index| {
+                        let params = params.randomized(*spread_m, *variation, rng);
+                        (format!("{}_{}", topic, index), VehicleSim::new(params))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Advance by one sample of length `dt` ending at `t_sec`, returning the
+    /// data for every topic it produced
+    fn sample(&mut self, topic: &str, t_sec: f64, dt: f64, rng: &mut StdRng) -> Vec<Generated> {
+        match self {
+            TopicGenerator::Signals(fields, shape) => {
+                let row: Map<String, Value> = fields
+                    .iter_mut()
+                    .map(|(name, signal)| (name.clone(), signal.sample(t_sec, rng)))
+                    .collect();
+                vec![Generated {
+                    topic: topic.to_string(),
+                    data: Value::Object(row),
+                    shape: Arc::clone(shape),
+                }]
+            }
+            TopicGenerator::TestData(vehicle) => {
+                vehicle.step(t_sec, dt);
+                vec![Generated::of(topic.to_string(), &vehicle.test_data())]
+            }
+            TopicGenerator::Vehicles(vehicles) => vehicles
+                .iter_mut()
+                .flat_map(|(namespace, vehicle)| {
+                    vehicle.step(t_sec, dt);
+                    [
+                        Generated::of(format!("{}/pose", namespace), &vehicle.pose()),
+                        Generated::of(format!("{}/twist", namespace), &vehicle.twist()),
+                        Generated::of(format!("{}/imu", namespace), &vehicle.imu()),
+                        Generated::of(format!("{}/battery", namespace), &vehicle.battery()),
+                    ]
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One topic's data from a generator step
+struct Generated {
+    topic: String,
+    data: Value,
+    /// Shape of the type `data` was serialized from, so its Arrow schema
+    /// doesn't change with the values
+    shape: Arc<Shape>,
+}
+
+impl Generated {
+    fn of<T: serde::Serialize + DeserializeOwned + 'static>(topic: String, data: &T) -> Self {
+        Self {
+            topic,
+            data: serde_json::to_value(data).expect("generated data always serializes"),
+            shape: Shape::of::<T>(),
+        }
+    }
+}
+
+/// Encode a generated sample for the wire, applying the value faults on the
+/// way
+fn encode_payload(
+    Generated { topic, mut data, shape }: Generated,
+    time: u64,
+    use_arrow_ipc: bool,
+    faults: &mut FaultInjector,
+    dt: f64,
+    rng: &mut StdRng,
+) -> Payload {
+    if use_arrow_ipc {
+        let batch = arrow_batch(&topic, time, &data, &shape)
+            .and_then(|batch| faults.corrupt_batch(&topic, batch, dt, rng));
+        match batch {
+            Ok(batch) => return Payload::Batch(batch),
+            Err(e) => error!("Error creating Arrow batch for {}: {}. Falling back to JSON", topic, e),
+        }
+    }
+
+    faults.corrupt_value(&topic, &mut data, dt, rng);
+    let sync_update = SyncUpdate::new(
+        topic,
+        vec![(time, data.to_string())],
+    );
+    Payload::Datapoint(sync_update.datapoint(time))
+}
+
+/// Spawn one task per configured topic, all sharing the same clock so their
+/// timestamps line up.
+pub fn spawn_generators(state: StateHandle, config: GeneratorConfig) {
+    let start_time = Instant::now();
+    let seed = config.seed;
+
+    for (index, topic) in config.topics.into_iter().enumerate() {
+        info!("Generating {} at {} Hz", topic.topic, topic.rate_hz);
+        if !topic.faults.is_clean() {
+            info!("Injecting faults into {}: {:?}", topic.topic, topic.faults);
+        }
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(index as u64)),
+            None => StdRng::from_entropy(),
+        };
+        tokio::spawn(run_topic(state.clone(), topic, start_time, rng));
+    }
+}
+
+/// Publish one topic onto the shared bus for the lifetime of the bridge.
+async fn run_topic(state: StateHandle, config: TopicConfig, start_time: Instant, mut rng: StdRng) {
+    let use_arrow_ipc = state.lock().unwrap().use_arrow_ipc;
+    let mut generator = TopicGenerator::new(&config.topic, &config.source, &mut rng);
+    let mut faults = FaultInjector::new(config.faults.clone());
+    let dt = 1.0 / config.rate_hz;
+    let mut ticker = tokio::time::interval(std::time::Duration::from_millis(1));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut sample: u64 = 0;
+
+    loop {
+        ticker.tick().await;
+
+        // Publish every sample that came due since the last wake-up, so rates
+        // above the timer resolution still produce the right number of rows
+        let due = (start_time.elapsed().as_secs_f64() * config.rate_hz) as u64;
+        while sample < due {
+            let t_sec = sample as f64 / config.rate_hz;
+            let time = (t_sec * 1000.0) as u64;
+            sample += 1;
+
+            // Generate and encode without the state locked, other topics and
+            // clients only wait for the publishing
+            let mut ready = Vec::new();
+            for generated in generator.sample(&config.topic, t_sec, dt, &mut rng) {
+                if faults.should_drop(&mut rng) {
+                    continue;
+                }
+                let topic = generated.topic.clone();
+                let payload = encode_payload(generated, time, use_arrow_ipc, &mut faults, dt, &mut rng);
+                ready.extend(faults.deliver(&topic, time, payload, &mut rng));
+            }
+
+            let mut state = state.lock().unwrap();
+            let dt_ms = time.saturating_sub(state.t_ms);
+            state.tick(dt_ms);
+            state.update_last_t();
+            for (topic, time, payload) in ready {
+                state.publish(None, &topic, time, payload);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_topic(topic: &str) -> Result<GeneratorConfig, String> {
+        GeneratorConfig::parse(&format!("[[topics]]\ntopic = \"t\"\n{}", topic)).map_err(|e| e.to_string())
+    }
+
+    fn signal(toml: &str) -> SignalState {
+        let field: FieldConfig = toml::from_str(&format!("name = \"f\"\n{}", toml)).unwrap();
+        SignalState::new(field.signal)
+    }
+
+    fn samples(signal: &mut SignalState, times: &[f64]) -> Vec<Value> {
+        let mut rng = StdRng::seed_from_u64(1);
+        times.iter().map(|t| signal.sample(*t, &mut rng)).collect()
+    }
+
+    #[test]
+    fn example_and_doc_configs_parse() {
+        let config = GeneratorConfig::parse(include_str!("../generators.example.toml")).unwrap();
+        assert_eq!(config.seed, Some(42));
+        assert!(config.topics.iter().any(|topic| matches!(topic.source, TopicSource::Fleet { agents: 10, .. })));
+
+        let config = parse_topic(
+            "rate_hz = 100\nkind = \"signals\"\nfields = [\n\
+             { name = \"sine\", type = \"sine\", frequency_hz = 0.5 },\n\
+             { name = \"mode\", type = \"state\", states = [\"idle\", \"drive\"], dwell_s = 2.0 },\n]\n\
+             [topics.faults]\nnoise_std = 0.1",
+        ).unwrap();
+        let topic = &config.topics[0];
+        assert_eq!(topic.faults.noise_std, 0.1);
+        let TopicSource::Signals { fields } = &topic.source else {
+            panic!("expected signals, got {:?}", topic.source);
+        };
+        assert_eq!(fields[0].signal, Signal::Sine { amplitude: 1.0, frequency_hz: 0.5, phase: 0.0, offset: 0.0 });
+        assert_eq!(fields[1].name, "mode");
+    }
+
+    #[test]
+    fn invalid_configs_are_rejected() {
+        let cases = [
+            ("rate_hz = 0\nkind = \"test_data\"", "positive rate_hz"),
+            ("rate_hz = 1001\nkind = \"test_data\"", "at most 1000"),
+            ("rate_hz = 10\nkind = \"test_data\"\n[topics.faults]\ndrop_probability = 2.0", "drop_probability"),
+            ("rate_hz = 10\nkind = \"signals\"\nfields = [{ name = \"s\", type = \"square\", duty = 1.5 }]", "duty"),
+            ("rate_hz = 10\nkind = \"signals\"\nfields = [{ name = \"s\", type = \"state\", states = [], dwell_s = 1.0 }]", "one state"),
+            ("rate_hz = 10\nkind = \"signals\"\nfields = [{ name = \"s\", type = \"state\", states = [\"a\"], dwell_s = 0.0 }]", "positive duration"),
+            ("rate_hz = 10\nkind = \"fleet\"\nagents = 0", "one agent"),
+            ("rate_hz = 10\nkind = \"vehicle\"\nwheelbase = -1.0", "positive wheelbase"),
+        ];
+        for (topic, error) in cases {
+            let result = parse_topic(topic);
+            assert!(result.as_ref().is_err_and(|e| e.contains(error)), "{:?} gave {:?}", topic, result);
+        }
+        assert!(parse_topic("rate_hz = 1000\nkind = \"test_data\"").is_ok());
+        assert!(GeneratorConfig::test_data(2000.0).validate().is_err());
+    }
+
+    #[test]
+    fn fleets_publish_every_agent_from_its_own_start() {
+        let config = parse_topic(
+            "rate_hz = 10\nkind = \"fleet\"\nagents = 3\nspread_m = 20.0\nstart = { x = 100.0, y = -50.0, z = 1.0 }",
+        ).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut generator = TopicGenerator::new("robot", &config.topics[0].source, &mut rng);
+        let generated = generator.sample("robot", 0.0, 0.1, &mut rng);
+
+        let topics: Vec<&str> = generated.iter().map(|generated| generated.topic.as_str()).collect();
+        let expected: Vec<String> = (0..3)
+            .flat_map(|index| ["pose", "twist", "imu", "battery"].map(|kind| format!("robot_{}/{}", index, kind)))
+            .collect();
+        assert_eq!(topics, expected);
+
+        let poses: Vec<&Value> = generated.iter()
+            .filter(|generated| generated.topic.ends_with("/pose"))
+            .map(|generated| &generated.data)
+            .collect();
+        for pose in &poses {
+            let position = &pose["position"];
+            assert!((position["x"].as_f64().unwrap() - 100.0).abs() <= 10.0, "{}", position);
+            assert!((position["y"].as_f64().unwrap() + 50.0).abs() <= 10.0, "{}", position);
+            assert_eq!(position["z"], 1.0);
+        }
+        for (index, pose) in poses.iter().enumerate() {
+            assert!(poses[..index].iter().all(|other| other["position"] != pose["position"]));
+            assert!(poses[..index].iter().all(|other| other["euler"]["z"] != pose["euler"]["z"]));
+        }
+        assert!(generated.iter().all(|generated| !matches!(*generated.shape, Shape::Infer)));
+    }
+
+    #[test]
+    fn periodic_signals_follow_their_waveform() {
+        let mut sine = signal("type = \"sine\"\namplitude = 2.0\nfrequency_hz = 0.5\noffset = 1.0");
+        let values = samples(&mut sine, &[0.0, 0.5, 1.0, 1.5]);
+        let expected = [1.0, 3.0, 1.0, -1.0];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!((value.as_f64().unwrap() - expected).abs() < 1e-9, "{} != {}", value, expected);
+        }
+
+        let mut square = signal("type = \"square\"\nduty = 0.25");
+        assert_eq!(samples(&mut square, &[0.0, 0.2, 0.3, 0.9, 1.1]), [1.0, 1.0, -1.0, -1.0, 1.0]);
+
+        let mut sawtooth = signal("type = \"sawtooth\"\namplitude = 2.0");
+        assert_eq!(samples(&mut sawtooth, &[0.0, 0.25, 0.5, 1.25]), [-2.0, -1.0, 0.0, -1.0]);
+
+        let mut step = signal("type = \"step\"\nbefore = 3.0\nafter = 4.0\nat_s = 1.0");
+        assert_eq!(samples(&mut step, &[0.0, 0.999, 1.0, 5.0]), [3.0, 3.0, 4.0, 4.0]);
+
+        // Starts at `start_hz`, so a quarter period of 1 Hz in is the peak
+        let mut chirp = signal("type = \"chirp\"\nstart_hz = 1.0\nend_hz = 1.0\nduration_s = 10.0");
+        let values = samples(&mut chirp, &[0.0, 0.25]);
+        assert!(values[0].as_f64().unwrap().abs() < 1e-9 && (values[1].as_f64().unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn random_signals_stay_in_bounds() {
+        let mut walk = signal("type = \"random_walk\"\nstart = 0.5\nstep_std = 10.0\nmin = 0.0\nmax = 1.0");
+        let times: Vec<f64> = (0..1000).map(|index| index as f64).collect();
+        let values = samples(&mut walk, &times);
+        assert!(values.iter().all(|value| (0.0..=1.0).contains(&value.as_f64().unwrap())));
+        assert!(values.iter().any(|value| value.as_f64() == Some(0.0)) && values.iter().any(|value| value.as_f64() == Some(1.0)));
+
+        let mut gaussian = signal("type = \"gaussian\"\nmean = 5.0\nstd = 0.1");
+        let values = samples(&mut gaussian, &times);
+        let mean = values.iter().map(|value| value.as_f64().unwrap()).sum::<f64>() / values.len() as f64;
+        assert!((mean - 5.0).abs() < 0.05, "mean {}", mean);
+    }
+
+    #[test]
+    fn states_cycle_every_dwell() {
+        let mut state = signal("type = \"state\"\nstates = [\"idle\", \"drive\", \"stop\"]\ndwell_s = 2.0");
+        assert_eq!(samples(&mut state, &[0.0, 1.9, 2.0, 4.5, 6.0]), ["idle", "idle", "drive", "stop", "idle"]);
+
+        let mut random = signal("type = \"state\"\nstates = [\"a\", \"b\"]\ndwell_s = 1.0\nrandom = true");
+        let times: Vec<f64> = (0..100).map(|index| index as f64 * 0.5).collect();
+        let values = samples(&mut random, &times);
+        assert!(values.chunks(2).all(|slot| slot[0] == slot[1]));
+        assert!(values.contains(&Value::from("a")) && values.contains(&Value::from("b")));
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, VecDeque};
+use crate::state::{BusMessage, Payload};
+use crate::ws_handler::SyncRequest;
+
+/// Bounds applied to every topic's buffer, `None` leaves that dimension unbounded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryLimits{
+    pub max_len: usize,
+    pub max_age_ms: Option<u64>,
+    pub max_bytes: Option<usize>,
+}
+
+impl Default for HistoryLimits{
+    fn default() -> Self{
+        Self{
+            max_len: 10_000,
+            max_age_ms: None,
+            max_bytes: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HistoryEntry{
+    pub time: u64,
+    pub size: usize,
+    pub payload: Payload,
+}
+
+/// Ring buffer of the most recent samples on a single topic
+#[derive(Debug, Clone, Default)]
+pub struct TopicHistory{
+    entries: VecDeque<HistoryEntry>,
+    bytes: usize,
+    latest_time: u64,
+}
+
+impl TopicHistory{
+    pub fn push(&mut self, time: u64, payload: Payload, limits: &HistoryLimits){
+        let size = payload.size_bytes();
+        self.bytes += size;
+        self.latest_time = self.latest_time.max(time);
+        self.entries.push_back(HistoryEntry{time, size, payload});
+        self.evict(limits);
+    }
+
+    fn evict(&mut self, limits: &HistoryLimits){
+        while let Some(oldest) = self.entries.front(){
+            let over_len = self.entries.len() > limits.max_len;
+            let over_age = limits.max_age_ms
+                .is_some_and(|max_age| self.latest_time.saturating_sub(oldest.time) > max_age);
+            let over_bytes = limits.max_bytes.is_some_and(|max_bytes| self.bytes > max_bytes);
+            if !(over_len || over_age || over_bytes){
+                break;
+            }
+
+            self.bytes -= oldest.size;
+            self.entries.pop_front();
+        }
+    }
+
+    /// Entries with `start <= time <= end`, in arrival order
+    pub fn range(&self, start: u64, end: u64) -> impl Iterator<Item = &HistoryEntry>{
+        self.entries.iter().filter(move |entry| entry.time >= start && entry.time <= end)
+    }
+}
+
+/// Per-topic history kept by the bridge so late clients can backfill
+#[derive(Debug, Clone, Default)]
+pub struct History{
+    pub limits: HistoryLimits,
+    topics: BTreeMap<String, TopicHistory>,
+}
+
+impl History{
+    pub fn new(limits: HistoryLimits) -> Self{
+        Self{
+            limits,
+            topics: BTreeMap::new(),
+        }
+    }
+
+    pub fn record(&mut self, topic: &str, time: u64, payload: Payload){
+        let limits = self.limits;
+        self.topics.entry(topic.to_string())
+            .or_default()
+            .push(time, payload, &limits);
+    }
+
+    /// Newest stored sample of every topic
+    pub fn latest(&self) -> Vec<BusMessage>{
+        self.topics.iter()
+            .filter_map(|(topic, history)| {
+                history.entries.back().map(|entry| BusMessage{
+                    source: None,
+                    topic: topic.clone(),
+                    payload: entry.payload.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Stored samples for every topic the request matches, within its range
+    pub fn query(&self, request: &SyncRequest) -> Vec<BusMessage>{
+        let Some((start, end)) = request.range else{
+            return Vec::new();
+        };
+
+        self.topics.iter()
+            .filter(|(topic, _)| request.matches(topic))
+            .flat_map(|(topic, history)| {
+                history.range(start, end).map(move |entry| BusMessage{
+                    source: None,
+                    topic: topic.clone(),
+                    payload: entry.payload.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::datapoint::DataPoint;
+
+    /// A payload of exactly `size` bytes
+    fn payload(size: usize) -> Payload{
+        Payload::Datapoint(DataPoint::with_json(String::new(), 0, "x".repeat(size)))
+    }
+
+    fn times(history: &TopicHistory) -> Vec<u64>{
+        history.entries.iter().map(|entry| entry.time).collect()
+    }
+
+    #[test]
+    fn evicts_oldest_beyond_max_len(){
+        let limits = HistoryLimits{max_len: 3, ..Default::default()};
+        let mut history = TopicHistory::default();
+        for time in 0..5{
+            history.push(time, payload(1), &limits);
+        }
+        assert_eq!(times(&history), vec![2, 3, 4]);
+        assert_eq!(history.bytes, 3);
+    }
+
+    #[test]
+    fn evicts_entries_older_than_max_age(){
+        let limits = HistoryLimits{max_age_ms: Some(100), ..Default::default()};
+        let mut history = TopicHistory::default();
+        for time in [0, 50, 100, 150, 201]{
+            history.push(time, payload(1), &limits);
+        }
+        // 201 - 100 is 101 ms, just over the limit
+        assert_eq!(times(&history), vec![150, 201]);
+    }
+
+    #[test]
+    fn evicts_oldest_beyond_max_bytes(){
+        let limits = HistoryLimits{max_bytes: Some(25), ..Default::default()};
+        let mut history = TopicHistory::default();
+        for time in 0..4{
+            history.push(time, payload(10), &limits);
+        }
+        assert_eq!(times(&history), vec![2, 3]);
+        assert_eq!(history.bytes, 20);
+    }
+
+    #[test]
+    fn keeps_a_single_oversized_entry_out(){
+        let limits = HistoryLimits{max_bytes: Some(5), ..Default::default()};
+        let mut history = TopicHistory::default();
+        history.push(0, payload(10), &limits);
+        assert!(history.entries.is_empty());
+        assert_eq!(history.bytes, 0);
+    }
+
+    #[test]
+    fn range_includes_both_ends(){
+        let mut history = TopicHistory::default();
+        for time in 0..10{
+            history.push(time, payload(1), &HistoryLimits::default());
+        }
+        let in_range: Vec<u64> = history.range(3, 5).map(|entry| entry.time).collect();
+        assert_eq!(in_range, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn record_applies_limits_per_topic(){
+        let mut history = History::new(HistoryLimits{max_len: 2, ..Default::default()});
+        for time in 0..5{
+            history.record("a", time, payload(1));
+        }
+        history.record("b", 0, payload(1));
+        assert_eq!(times(&history.topics["a"]), vec![3, 4]);
+        assert_eq!(times(&history.topics["b"]), vec![0]);
+
+        let latest: Vec<String> = history.latest().into_iter().map(|message| message.topic).collect();
+        assert_eq!(latest, vec!["a", "b"]);
+    }
+}
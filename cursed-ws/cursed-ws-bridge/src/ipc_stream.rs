@@ -0,0 +1,275 @@
+//! Arrow IPC stream framing for binary WebSocket frames.
+//!
+//! Each connection keeps one Arrow stream per topic. The schema is sent once as
+//! its own frame, followed by frames that carry only record-batch messages. A
+//! new schema frame is sent whenever a topic's schema changes.
+//!
+//! Every binary frame starts with a header naming the topic:
+//!
+//! | bytes | field                                        |
+//! |-------|----------------------------------------------|
+//! | 1     | kind: `0` = schema, `1` = record batch       |
+//! | 2     | topic length, little endian, at most 65535   |
+//! | n     | topic, UTF-8                                 |
+//! | ..    | Arrow IPC stream message(s)                  |
+
+use arrow::buffer::Buffer;
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::ipc::reader::StreamDecoder;
+use arrow::ipc::writer::{IpcWriteOptions, StreamWriter};
+use arrow::ipc::MetadataVersion;
+use arrow::record_batch::RecordBatch;
+use std::collections::HashMap;
+
+/// Topic streams a connection may publish on at once. Beyond that, the
+/// least recently used one is dropped and has to send its schema again.
+pub const MAX_DECODED_TOPICS: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FrameKind {
+    Schema = 0,
+    RecordBatch = 1,
+}
+
+impl TryFrom<u8> for FrameKind {
+    type Error = ArrowError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FrameKind::Schema),
+            1 => Ok(FrameKind::RecordBatch),
+            other => Err(ArrowError::ParseError(format!("Unknown frame kind {}", other))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Frame<'a> {
+    pub kind: FrameKind,
+    pub topic: &'a str,
+    pub ipc: &'a [u8],
+}
+
+impl<'a> Frame<'a> {
+    pub fn encode(kind: FrameKind, topic: &str, ipc: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        let topic_len = u16::try_from(topic.len()).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "Topic is {} bytes long, frames allow at most {}",
+                topic.len(),
+                u16::MAX
+            ))
+        })?;
+
+        let mut bytes = Vec::with_capacity(3 + topic.len() + ipc.len());
+        bytes.push(kind as u8);
+        bytes.extend_from_slice(&topic_len.to_le_bytes());
+        bytes.extend_from_slice(topic.as_bytes());
+        bytes.extend_from_slice(ipc);
+        Ok(bytes)
+    }
+
+    pub fn decode(bytes: &'a [u8]) -> Result<Self, ArrowError> {
+        if bytes.len() < 3 {
+            return Err(ArrowError::ParseError("Frame shorter than its header".to_string()));
+        }
+
+        let kind = FrameKind::try_from(bytes[0])?;
+        let topic_len = u16::from_le_bytes([bytes[1], bytes[2]]) as usize;
+        let topic_end = 3 + topic_len;
+        if bytes.len() < topic_end {
+            return Err(ArrowError::ParseError("Frame topic is truncated".to_string()));
+        }
+
+        let topic = std::str::from_utf8(&bytes[3..topic_end])
+            .map_err(|e| ArrowError::ParseError(format!("Frame topic is not UTF-8: {}", e)))?;
+
+        Ok(Self {
+            kind,
+            topic,
+            ipc: &bytes[topic_end..],
+        })
+    }
+}
+
+/// Per-connection writer that turns record batches into stream frames
+#[derive(Default)]
+pub struct StreamEncoder {
+    writers: HashMap<String, (SchemaRef, StreamWriter<Vec<u8>>)>,
+}
+
+impl StreamEncoder {
+    /// Encode a batch for `topic`, preceded by a schema frame when the topic
+    /// is new to this connection or its schema changed.
+    pub fn encode(&mut self, topic: &str, batch: &RecordBatch) -> Result<Vec<Vec<u8>>, ArrowError> {
+        let mut frames = Vec::new();
+
+        let schema_changed = self.writers.get(topic)
+            .is_none_or(|(schema, _)| *schema != batch.schema());
+        if schema_changed {
+            // 8-byte alignment instead of the default 64 keeps small batches small
+            let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V5)?;
+            let mut writer = StreamWriter::try_new_with_options(Vec::new(), &batch.schema(), options)?;
+            let schema_bytes = std::mem::take(writer.get_mut());
+            frames.push(Frame::encode(FrameKind::Schema, topic, &schema_bytes)?);
+            self.writers.insert(topic.to_string(), (batch.schema(), writer));
+        }
+
+        let (_, writer) = self.writers.get_mut(topic).expect("writer inserted above");
+        writer.write(batch)?;
+        let batch_bytes = std::mem::take(writer.get_mut());
+        frames.push(Frame::encode(FrameKind::RecordBatch, topic, &batch_bytes)?);
+
+        Ok(frames)
+    }
+}
+
+/// Per-connection reader for frames sent by publishing clients
+#[derive(Default)]
+pub struct StreamFrameDecoder {
+    /// Decoder of each topic and when it was last used
+    decoders: HashMap<String, (u64, StreamDecoder)>,
+    frames: u64,
+}
+
+impl StreamFrameDecoder {
+    /// Decode one frame into the batches it carries. Schema frames reset the
+    /// topic's stream and may carry batches of their own.
+    pub fn decode(&mut self, bytes: &[u8]) -> Result<(String, Vec<RecordBatch>), ArrowError> {
+        let frame = Frame::decode(bytes)?;
+        self.frames += 1;
+
+        if frame.kind == FrameKind::Schema {
+            if self.decoders.len() >= MAX_DECODED_TOPICS && !self.decoders.contains_key(frame.topic) {
+                self.evict_least_recently_used();
+            }
+            self.decoders.insert(frame.topic.to_string(), (self.frames, StreamDecoder::new()));
+        }
+
+        let (last_used, decoder) = self.decoders.get_mut(frame.topic).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Record batch for {} received before its schema",
+                frame.topic
+            ))
+        })?;
+        *last_used = self.frames;
+
+        let mut buffer = Buffer::from(frame.ipc.to_vec());
+        let mut batches = Vec::new();
+        while !buffer.is_empty() {
+            if let Some(batch) = decoder.decode(&mut buffer)? {
+                batches.push(batch);
+            }
+        }
+
+        Ok((frame.topic.to_string(), batches))
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self.decoders.iter()
+            .min_by_key(|(_, (last_used, _))| *last_used)
+            .map(|(topic, _)| topic.clone());
+        if let Some(topic) = oldest {
+            self.decoders.remove(&topic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float64Array, Int64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn batch(values: &[f64]) -> RecordBatch {
+        let schema = Schema::new(vec![Field::new("x", DataType::Float64, false)]);
+        RecordBatch::try_new(Arc::new(schema), vec![Arc::new(Float64Array::from(values.to_vec()))]).unwrap()
+    }
+
+    #[test]
+    fn frame_round_trips() {
+        let bytes = Frame::encode(FrameKind::RecordBatch, "robot/pose", &[1, 2, 3]).unwrap();
+        assert_eq!(&bytes[..3], &[1, 10, 0]);
+
+        let frame = Frame::decode(&bytes).unwrap();
+        assert_eq!(frame.kind, FrameKind::RecordBatch);
+        assert_eq!(frame.topic, "robot/pose");
+        assert_eq!(frame.ipc, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn encode_rejects_topics_longer_than_the_header_allows() {
+        let longest = "t".repeat(u16::MAX as usize);
+        let bytes = Frame::encode(FrameKind::Schema, &longest, &[]).unwrap();
+        assert_eq!(Frame::decode(&bytes).unwrap().topic.len(), u16::MAX as usize);
+
+        let too_long = "t".repeat(u16::MAX as usize + 1);
+        assert!(Frame::encode(FrameKind::Schema, &too_long, &[]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_malformed_headers() {
+        assert!(Frame::decode(&[0, 1]).is_err());
+        // Topic of 5 bytes with only 2 present
+        assert!(Frame::decode(&[0, 5, 0, b'a', b'b']).is_err());
+        assert!(Frame::decode(&[7, 0, 0]).is_err());
+        assert!(Frame::decode(&[0, 1, 0, 0xff]).is_err());
+    }
+
+    #[test]
+    fn schema_is_sent_once_per_topic_and_again_when_it_changes() {
+        let mut encoder = StreamEncoder::default();
+        let mut decoder = StreamFrameDecoder::default();
+
+        let first = encoder.encode("a", &batch(&[1.0, 2.0])).unwrap();
+        let kinds: Vec<FrameKind> = first.iter().map(|frame| Frame::decode(frame).unwrap().kind).collect();
+        assert_eq!(kinds, vec![FrameKind::Schema, FrameKind::RecordBatch]);
+
+        let second = encoder.encode("a", &batch(&[3.0])).unwrap();
+        assert_eq!(second.len(), 1);
+
+        let mut received = Vec::new();
+        for frame in first.iter().chain(&second) {
+            let (topic, batches) = decoder.decode(frame).unwrap();
+            assert_eq!(topic, "a");
+            received.extend(batches);
+        }
+        assert_eq!(received, vec![batch(&[1.0, 2.0]), batch(&[3.0])]);
+
+        let schema = Schema::new(vec![Field::new("n", DataType::Int64, false)]);
+        let changed = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(Int64Array::from(vec![4]))]).unwrap();
+        let third = encoder.encode("a", &changed).unwrap();
+        assert_eq!(third.len(), 2);
+        assert_eq!(Frame::decode(&third[0]).unwrap().kind, FrameKind::Schema);
+        let batches: Vec<RecordBatch> = third.iter().flat_map(|frame| decoder.decode(frame).unwrap().1).collect();
+        assert_eq!(batches, vec![changed]);
+    }
+
+    #[test]
+    fn batch_before_schema_is_an_error() {
+        let frames = StreamEncoder::default().encode("a", &batch(&[1.0])).unwrap();
+        assert!(StreamFrameDecoder::default().decode(&frames[1]).is_err());
+    }
+
+    #[test]
+    fn least_recently_used_topic_is_dropped_beyond_the_cap() {
+        let mut encoder = StreamEncoder::default();
+        let mut decoder = StreamFrameDecoder::default();
+        let mut batch_frames = Vec::new();
+        for index in 0..MAX_DECODED_TOPICS {
+            let frames = encoder.encode(&index.to_string(), &batch(&[1.0])).unwrap();
+            decoder.decode(&frames[0]).unwrap();
+            batch_frames.push(encoder.encode(&index.to_string(), &batch(&[2.0])).unwrap().remove(0));
+        }
+        // Topic 0 stays in use, so topic 1 is the one to go
+        decoder.decode(&batch_frames[0]).unwrap();
+
+        let frames = encoder.encode("new", &batch(&[1.0])).unwrap();
+        decoder.decode(&frames[0]).unwrap();
+        assert_eq!(decoder.decoders.len(), MAX_DECODED_TOPICS);
+        assert!(decoder.decode(&batch_frames[0]).is_ok());
+        assert!(decoder.decode(&batch_frames[1]).is_err());
+    }
+}
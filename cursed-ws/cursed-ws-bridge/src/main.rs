@@ -1,14 +1,27 @@
 use warp::Filter;
-use warp::{ Rejection};
 use clap::Parser;
 
 mod state;
+mod history;
+mod batcher;
+mod ipc_stream;
 mod ws_handler;
 mod test_data;
 mod datapoint;
+mod flatten;
+mod arrow_serde;
+mod generator;
+mod vehicle;
+mod faults;
+mod replay;
+mod recorder;
+mod mcap;
+mod foxglove;
+#[cfg(test)]
+mod test_util;
 
 // Import std::path for handling file paths
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Command line arguments for the WebSocket bridge
 #[derive(Parser, Debug, Clone)]
@@ -22,13 +35,69 @@ pub struct WSBridgeArgs {
     #[clap(short, long, default_value_t = 3031)]
     pub port: u16,
 
-    /// Data send rate in Hz
+    /// Data send rate in Hz, at most 1000, used when no generator config is given
     #[clap(short, long, default_value_t = 1000.0)]
     pub send_rate_hz: f64,
+
+    /// TOML file listing the topics and signals to generate
+    #[clap(long)]
+    pub generator_config: Option<PathBuf>,
+
+    /// CSV (`time,key,value`), MCAP or Arrow IPC recording to publish instead of generated data
+    #[clap(long)]
+    pub replay: Option<PathBuf>,
+
+    /// Playback speed factor for --replay
+    #[clap(long, default_value_t = 1.0)]
+    pub replay_speed: f64,
+
+    /// Restart --replay from the beginning when it ends
+    #[clap(long)]
+    pub replay_loop: bool,
+
+    /// Directory to record everything published on the bridge into
+    #[clap(long)]
+    pub record: Option<PathBuf>,
+
+    /// File format of --record
+    #[clap(long, value_enum, default_value_t = recorder::RecordFormat::Arrow)]
+    pub record_format: recorder::RecordFormat,
+
+    /// Message encoding of MCAP recordings
+    #[clap(long, value_enum, default_value_t = mcap::McapEncoding::Json)]
+    pub mcap_encoding: mcap::McapEncoding,
+
+    /// Start a new recording file once the current one reaches this many bytes
+    #[clap(long, default_value_t = 64 * 1024 * 1024)]
+    pub record_max_file_bytes: u64,
+
+    /// Start a new recording file once the current one is this many seconds old
+    #[clap(long, default_value_t = 600)]
+    pub record_max_file_age_s: u64,
     
     /// Use Arrow IPC binary format instead of JSON
     #[clap(long, default_value_t = true)]
     pub use_arrow_ipc: bool,
+
+    /// Window in ms over which rows are combined into one Arrow batch, 0 disables batching
+    #[clap(long, default_value_t = 20)]
+    pub flush_interval_ms: u64,
+
+    /// Flush a topic early once this many rows are pending
+    #[clap(long, default_value_t = 1024)]
+    pub flush_max_rows: usize,
+
+    /// Maximum number of messages kept per topic for range queries
+    #[clap(long, default_value_t = 10_000)]
+    pub history_len: usize,
+
+    /// Drop history older than this many ms behind the newest message on a topic
+    #[clap(long)]
+    pub history_max_age_ms: Option<u64>,
+
+    /// Maximum bytes of history kept per topic
+    #[clap(long)]
+    pub history_max_bytes: Option<usize>,
 }
 
 #[tokio::main]
@@ -40,15 +109,91 @@ async fn main() {
     let mut state = state::WSBridgeState::new();
     state.use_arrow_ipc = args.use_arrow_ipc;
     state.send_rate_hz = args.send_rate_hz;
-    let state = state.as_handle();
-    
+    state.flush_policy = batcher::FlushPolicy{
+        interval_ms: args.flush_interval_ms,
+        max_rows: args.flush_max_rows,
+    };
+    state.history = history::History::new(history::HistoryLimits{
+        max_len: args.history_len,
+        max_age_ms: args.history_max_age_ms,
+        max_bytes: args.history_max_bytes,
+    });
+    let state = state.into_handle();
+
+    // Start before anything publishes so the recording starts complete
+    let recorder = args.record.as_ref().map(|dir| {
+        let options = recorder::RecordOptions{
+            format: args.record_format,
+            mcap_encoding: args.mcap_encoding,
+            max_file_bytes: args.record_max_file_bytes,
+            max_file_age: std::time::Duration::from_secs(args.record_max_file_age_s),
+        };
+        let recorder = recorder::Recorder::create(dir, options)
+            .and_then(|recorder| recorder::spawn_recorder(state.clone(), recorder));
+        match recorder {
+            Ok(recorder) => recorder,
+            Err(e) => {
+                log::error!("Failed to start recording in {}: {}", dir.display(), e);
+                std::process::exit(1);
+            }
+        }
+    });
+
+    let generator_config = match &args.generator_config {
+        Some(path) => match generator::GeneratorConfig::load(path) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                log::error!("Failed to load generator config {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        },
+        // Replaying a recording replaces the default test data
+        None if args.replay.is_some() => None,
+        None => {
+            let config = generator::GeneratorConfig::test_data(args.send_rate_hz);
+            if let Err(e) = config.validate() {
+                log::error!("Invalid --send-rate-hz: {}", e);
+                std::process::exit(1);
+            }
+            Some(config)
+        }
+    };
+    if let Some(generator_config) = generator_config {
+        generator::spawn_generators(state.clone(), generator_config);
+    }
+
+    if let Some(path) = &args.replay {
+        if !args.replay_speed.is_finite() || args.replay_speed <= 0.0 {
+            log::error!("--replay-speed must be positive");
+            std::process::exit(1);
+        }
+        let datapoints = match replay::load(path) {
+            Ok(datapoints) => datapoints,
+            Err(e) => {
+                log::error!("Failed to load recording {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        };
+        let options = replay::ReplayOptions{
+            speed: args.replay_speed,
+            looped: args.replay_loop,
+        };
+        tokio::spawn(replay::run_replay(state.clone(), datapoints, options));
+    }
+
     // WebSocket route
     let ws_route = warp::path("ws")
         // The `ws()` filter will prepare the Websocket handshake.
         .and(warp::ws())
-        .and(with_state(state)) 
+        .and(with_state(state.clone())) 
         .and_then(ws_handler::ws_handler);
 
+    // Foxglove WebSocket protocol over the same bus
+    let foxglove_route = warp::path("foxglove")
+        .and(warp::ws())
+        .and(with_state(state))
+        .and_then(foxglove::foxglove_handler);
+
     // Static files route - serve files from the static directory
     let static_dir = Path::new("static");
     let static_route = warp::fs::dir(static_dir.to_path_buf());
@@ -58,7 +203,7 @@ async fn main() {
         .and(warp::fs::file(static_dir.join("index.html")));
     
     // Combine all routes
-    let routes = ws_route.or(index_route).or(static_route);
+    let routes = ws_route.or(foxglove_route).or(index_route).or(static_route);
 
     println!("Server started at http://0.0.0.0:{}", args.port);
     if args.use_arrow_ipc {
@@ -67,7 +212,14 @@ async fn main() {
         println!("Using JSON format for data transmission");
     }
     
-    warp::serve(routes).run(([0, 0, 0, 0], args.port)).await;
+    tokio::select! {
+        _ = warp::serve(routes).run(([0, 0, 0, 0], args.port)) => {}
+        _ = tokio::signal::ctrl_c() => log::info!("Shutting down"),
+    }
+
+    if let Some(recorder) = recorder {
+        recorder.stop().await;
+    }
 }
 
 fn with_state(state: state::StateHandle) -> impl Filter<Extract = (state::StateHandle,), Error = std::convert::Infallible> + Clone {
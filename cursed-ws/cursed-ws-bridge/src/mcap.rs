@@ -0,0 +1,660 @@
+//! Reading and writing [MCAP](https://mcap.dev/spec) files.
+//!
+//! The writer produces unchunked files: a header, a schema and channel per
+//! topic as it first appears, one message record per row or batch, then a
+//! summary section repeating the schemas and channels. CRCs are left at zero,
+//! which the spec reads as "not computed". Two message encodings are written:
+//!
+//! | encoding | schema                                    | message                                   |
+//! |----------|-------------------------------------------|-------------------------------------------|
+//! | `json`   | `jsonschema` of the nested payload        | one JSON object per row                   |
+//! | `arrow`  | `arrow_ipc`, the Arrow IPC schema message | `arrow_ipc`, one IPC record batch message |
+//!
+//! Arrow messages carry the flattened `topic`, `time` and data columns the
+//! bridge sends, so a message may hold several rows. To decode one, append
+//! it to its schema's data and read the result as an Arrow IPC stream.
+//!
+//! The reader understands both, in chunked or unchunked files compressed with
+//! zstd, lz4 or nothing. Channels in other encodings are skipped.
+
+use crate::arrow_serde::json_schema;
+use crate::datapoint::DataPoint;
+use arrow::array::{ArrayRef, StringArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::ipc::reader::StreamReader;
+use arrow::ipc::writer::{IpcWriteOptions, StreamWriter};
+use arrow::ipc::MetadataVersion;
+use arrow::record_batch::RecordBatch;
+use log::warn;
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Cursor, Read, Write};
+use std::sync::Arc;
+
+const MAGIC: &[u8] = b"\x89MCAP0\r\n";
+
+const OP_HEADER: u8 = 0x01;
+const OP_FOOTER: u8 = 0x02;
+const OP_SCHEMA: u8 = 0x03;
+const OP_CHANNEL: u8 = 0x04;
+const OP_MESSAGE: u8 = 0x05;
+const OP_CHUNK: u8 = 0x06;
+const OP_DATA_END: u8 = 0x0F;
+
+const NANOS_PER_MS: u64 = 1_000_000;
+
+/// Largest chunk the reader decompresses
+const MAX_CHUNK_BYTES: usize = 256 << 20;
+/// Chunks claiming to expand by more than this are rejected as corrupt
+const MAX_COMPRESSION_RATIO: usize = 4096;
+
+/// How messages are encoded in written files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum McapEncoding {
+    /// JSON messages with JSON schemas, readable by most MCAP tools
+    Json,
+    /// Arrow IPC record batches, keeping the Arrow types exactly
+    Arrow,
+}
+
+fn parse_error(message: impl Into<String>) -> ArrowError {
+    ArrowError::ParseError(message.into())
+}
+
+fn io_error(e: std::io::Error) -> ArrowError {
+    ArrowError::IoError(e.to_string(), e)
+}
+
+/// Builds the content of one record
+#[derive(Default)]
+struct RecordBuilder(Vec<u8>);
+
+impl RecordBuilder {
+    fn u16(mut self, value: u16) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn u32(mut self, value: u32) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn u64(mut self, value: u64) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn string(self, value: &str) -> Self {
+        self.bytes32(value.as_bytes())
+    }
+
+    fn bytes32(mut self, value: &[u8]) -> Self {
+        self = self.u32(value.len() as u32);
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    /// Raw bytes running to the end of the record
+    fn rest(mut self, value: &[u8]) -> Self {
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    /// Opcode, length and content
+    fn finish(self, opcode: u8) -> Vec<u8> {
+        let mut record = Vec::with_capacity(9 + self.0.len());
+        record.push(opcode);
+        record.extend_from_slice(&(self.0.len() as u64).to_le_bytes());
+        record.extend_from_slice(&self.0);
+        record
+    }
+}
+
+/// A channel of a topic, one per schema the topic was written with
+struct TopicChannel {
+    id: u16,
+    schema: SchemaRef,
+    sequence: u32,
+    /// Continues the stream started by the schema record, `arrow` only
+    encoder: Option<StreamWriter<Vec<u8>>>,
+}
+
+pub struct McapWriter<W: Write> {
+    output: W,
+    encoding: McapEncoding,
+    position: u64,
+    /// Schema and channel records, repeated in the summary
+    summary: Vec<Vec<u8>>,
+    channels: HashMap<String, Vec<TopicChannel>>,
+    next_id: u16,
+}
+
+impl<W: Write> McapWriter<W> {
+    pub fn new(output: W, encoding: McapEncoding) -> Result<Self, ArrowError> {
+        let mut writer = Self {
+            output,
+            encoding,
+            position: 0,
+            summary: Vec::new(),
+            channels: HashMap::new(),
+            next_id: 1,
+        };
+        writer.write_bytes(MAGIC)?;
+        let library = concat!("cursed-ws-bridge ", env!("CARGO_PKG_VERSION"));
+        let header = RecordBuilder::default().string("").string(library).finish(OP_HEADER);
+        writer.write_bytes(&header)?;
+        Ok(writer)
+    }
+
+    /// Bytes written so far
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.output.flush().map_err(io_error)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), ArrowError> {
+        self.output.write_all(bytes).map_err(io_error)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Write a flattened `topic`, `time` and data columns batch
+    pub fn write(&mut self, topic: &str, batch: &RecordBatch) -> Result<(), ArrowError> {
+        // A topic going back to an earlier schema reuses its channel
+        let existing = self.channels.get(topic)
+            .and_then(|channels| channels.iter().position(|channel| channel.schema == batch.schema()));
+        let index = match existing {
+            Some(index) => index,
+            None => self.add_channel(topic, batch)?,
+        };
+
+        let messages: Vec<(u64, Vec<u8>)> = match self.encoding {
+            McapEncoding::Json => DataPoint::from_record_batch(batch)?
+                .iter()
+                .map(|datapoint| {
+                    let data: Value = datapoint.to_data()?;
+                    Ok((datapoint.time, data.to_string().into_bytes()))
+                })
+                .collect::<Result<_, ArrowError>>()?,
+            McapEncoding::Arrow => {
+                let Some(time) = crate::datapoint::batch_time_range(batch)?.map(|(start, _)| start) else {
+                    return Ok(());
+                };
+                let channel = &mut self.channels.get_mut(topic).expect("channel added above")[index];
+                let encoder = channel.encoder.as_mut().expect("arrow channels keep an encoder");
+                encoder.write(batch)?;
+                vec![(time, std::mem::take(encoder.get_mut()))]
+            }
+        };
+
+        for (time, data) in messages {
+            let channel = &mut self.channels.get_mut(topic).expect("channel added above")[index];
+            let record = RecordBuilder::default()
+                .u16(channel.id)
+                .u32(channel.sequence)
+                .u64(time.saturating_mul(NANOS_PER_MS))
+                .u64(time.saturating_mul(NANOS_PER_MS))
+                .rest(&data)
+                .finish(OP_MESSAGE);
+            channel.sequence = channel.sequence.wrapping_add(1);
+            self.write_bytes(&record)?;
+        }
+        Ok(())
+    }
+
+    /// Add a channel for the schema of `batch`, returning its index among the
+    /// topic's channels
+    fn add_channel(&mut self, topic: &str, batch: &RecordBatch) -> Result<usize, ArrowError> {
+        let id = self.next_id;
+        self.next_id = self.next_id.checked_add(1)
+            .ok_or_else(|| ArrowError::InvalidArgumentError("Too many MCAP channels".to_string()))?;
+
+        let (schema_encoding, schema_data, message_encoding, encoder) = match self.encoding {
+            McapEncoding::Json => {
+                let mut schema = json_schema(&crate::datapoint::batch_data_type(batch)?);
+                schema["title"] = Value::from(topic);
+                ("jsonschema", schema.to_string().into_bytes(), "json", None)
+            }
+            McapEncoding::Arrow => {
+                let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V5)?;
+                let mut encoder = StreamWriter::try_new_with_options(Vec::new(), &batch.schema(), options)?;
+                let schema_data = std::mem::take(encoder.get_mut());
+                ("arrow_ipc", schema_data, "arrow_ipc", Some(encoder))
+            }
+        };
+
+        // One schema per channel, schema and channel ids are separate spaces
+        let schema_record = RecordBuilder::default()
+            .u16(id)
+            .string(topic)
+            .string(schema_encoding)
+            .bytes32(&schema_data)
+            .finish(OP_SCHEMA);
+        let channel_record = RecordBuilder::default()
+            .u16(id)
+            .u16(id)
+            .string(topic)
+            .string(message_encoding)
+            .u32(0)
+            .finish(OP_CHANNEL);
+        self.write_bytes(&schema_record)?;
+        self.write_bytes(&channel_record)?;
+        self.summary.push(schema_record);
+        self.summary.push(channel_record);
+
+        let channels = self.channels.entry(topic.to_string()).or_default();
+        channels.push(TopicChannel {
+            id,
+            schema: batch.schema(),
+            sequence: 0,
+            encoder,
+        });
+        Ok(channels.len() - 1)
+    }
+
+    /// End the data section and write the summary, footer and closing magic
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        let data_end = RecordBuilder::default().u32(0).finish(OP_DATA_END);
+        self.write_bytes(&data_end)?;
+
+        let summary_start = if self.summary.is_empty() { 0 } else { self.position };
+        for record in std::mem::take(&mut self.summary) {
+            self.write_bytes(&record)?;
+        }
+
+        let footer = RecordBuilder::default().u64(summary_start).u64(0).u32(0).finish(OP_FOOTER);
+        self.write_bytes(&footer)?;
+        self.write_bytes(MAGIC)?;
+        self.output.flush().map_err(io_error)
+    }
+}
+
+/// Reads the fields of one record's content
+struct RecordReader<'a> {
+    content: &'a [u8],
+}
+
+impl<'a> RecordReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ArrowError> {
+        if self.content.len() < len {
+            return Err(parse_error("MCAP record is truncated"));
+        }
+        let (taken, rest) = self.content.split_at(len);
+        self.content = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, ArrowError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, ArrowError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, ArrowError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> Result<&'a str, ArrowError> {
+        let bytes = self.bytes32()?;
+        std::str::from_utf8(bytes).map_err(|e| parse_error(format!("MCAP string is not UTF-8: {}", e)))
+    }
+
+    fn bytes32(&mut self) -> Result<&'a [u8], ArrowError> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn bytes64(&mut self) -> Result<&'a [u8], ArrowError> {
+        let len = self.u64()? as usize;
+        self.take(len)
+    }
+}
+
+struct ReadSchema {
+    encoding: String,
+    data: Vec<u8>,
+}
+
+struct ReadChannel {
+    topic: String,
+    schema_id: u16,
+    message_encoding: String,
+}
+
+#[derive(Default)]
+struct McapReader {
+    schemas: HashMap<u16, ReadSchema>,
+    channels: HashMap<u16, ReadChannel>,
+    datapoints: Vec<DataPoint>,
+    /// Messages skipped per unsupported encoding
+    skipped: BTreeMap<String, usize>,
+}
+
+impl McapReader {
+    /// Handle records until the end of `bytes` or of the data section,
+    /// returning whether the data section ended
+    fn read_records(&mut self, mut bytes: &[u8]) -> Result<bool, ArrowError> {
+        while !bytes.is_empty() {
+            let mut reader = RecordReader { content: bytes };
+            let (Ok(opcode), Ok(content)) = (reader.take(1), reader.bytes64()) else {
+                // A file cut short by a crash ends mid-record
+                return Ok(false);
+            };
+            let opcode = opcode[0];
+            bytes = reader.content;
+
+            let mut content = RecordReader { content };
+            match opcode {
+                OP_SCHEMA => {
+                    let id = content.u16()?;
+                    let _name = content.string()?;
+                    let encoding = content.string()?.to_string();
+                    let data = content.bytes32()?.to_vec();
+                    self.schemas.insert(id, ReadSchema { encoding, data });
+                }
+                OP_CHANNEL => {
+                    let id = content.u16()?;
+                    let schema_id = content.u16()?;
+                    let topic = content.string()?.to_string();
+                    let message_encoding = content.string()?.to_string();
+                    self.channels.insert(id, ReadChannel { topic, schema_id, message_encoding });
+                }
+                OP_MESSAGE => {
+                    let channel_id = content.u16()?;
+                    let _sequence = content.u32()?;
+                    let log_time = content.u64()?;
+                    let _publish_time = content.u64()?;
+                    self.read_message(channel_id, log_time / NANOS_PER_MS, content.content)?;
+                }
+                OP_CHUNK => {
+                    let _start_time = content.u64()?;
+                    let _end_time = content.u64()?;
+                    let uncompressed_size = content.u64()?;
+                    let _crc = content.u32()?;
+                    let compression = content.string()?;
+                    let records = content.bytes64()?;
+                    let records = decompress(compression, records, uncompressed_size)?;
+                    self.read_records(&records)?;
+                }
+                OP_DATA_END | OP_FOOTER => return Ok(true),
+                // Indexes, statistics, attachments and metadata carry no messages
+                _ => {}
+            }
+        }
+        Ok(false)
+    }
+
+    fn read_message(&mut self, channel_id: u16, time: u64, data: &[u8]) -> Result<(), ArrowError> {
+        let channel = self.channels.get(&channel_id)
+            .ok_or_else(|| parse_error(format!("MCAP message on unknown channel {}", channel_id)))?;
+
+        match channel.message_encoding.as_str() {
+            "json" => {
+                let value: Value = serde_json::from_slice(data)
+                    .map_err(|e| parse_error(format!("Bad JSON message on {}: {}", channel.topic, e)))?;
+                // Struct payloads only, scalars get a column like CSV values do
+                let value = if value.is_object() { value } else { json!({ "value": value }) };
+                self.datapoints.push(DataPoint::from_data(channel.topic.clone(), time, &value)?);
+            }
+            "arrow_ipc" => {
+                let schema = self.schemas.get(&channel.schema_id)
+                    .filter(|schema| schema.encoding == "arrow_ipc")
+                    .ok_or_else(|| parse_error(format!("No Arrow schema for {}", channel.topic)))?;
+                let stream = [schema.data.as_slice(), data].concat();
+                for batch in StreamReader::try_new(Cursor::new(stream), None)? {
+                    let batch = with_topic_and_time(batch?, &channel.topic, time)?;
+                    self.datapoints.extend(DataPoint::from_record_batch(&batch)?);
+                }
+            }
+            encoding => *self.skipped.entry(encoding.to_string()).or_default() += 1,
+        }
+        Ok(())
+    }
+}
+
+/// Decompress a chunk's records. The size in the chunk header is checked
+/// against the compressed size before anything is allocated, so a corrupt or
+/// hostile file can't ask for more memory than its own size justifies.
+fn decompress(compression: &str, records: &[u8], uncompressed_size: u64) -> Result<Vec<u8>, ArrowError> {
+    let limit = records.len().saturating_mul(MAX_COMPRESSION_RATIO).min(MAX_CHUNK_BYTES);
+    let size = usize::try_from(uncompressed_size).ok()
+        .filter(|size| *size <= limit)
+        .ok_or_else(|| parse_error(format!(
+            "MCAP chunk of {} bytes claims {} bytes uncompressed, at most {} are read",
+            records.len(),
+            uncompressed_size,
+            limit
+        )))?;
+
+    let decompressed = match compression {
+        "" => records.to_vec(),
+        "zstd" => zstd::bulk::decompress(records, size).map_err(io_error)?,
+        "lz4" => {
+            let mut decompressed = Vec::with_capacity(size);
+            lz4_flex::frame::FrameDecoder::new(records)
+                .take(size as u64 + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(io_error)?;
+            decompressed
+        }
+        other => return Err(parse_error(format!("Unsupported MCAP chunk compression {:?}", other))),
+    };
+    if decompressed.len() != size {
+        return Err(parse_error(format!(
+            "MCAP chunk decompressed to {} bytes, its header says {}",
+            decompressed.len(),
+            size
+        )));
+    }
+    Ok(decompressed)
+}
+
+/// Add the channel's topic and the message time to Arrow batches written by
+/// other tools, which don't carry them as columns
+fn with_topic_and_time(batch: RecordBatch, topic: &str, time: u64) -> Result<RecordBatch, ArrowError> {
+    if batch.column_by_name("topic").is_some() && batch.column_by_name("time").is_some() {
+        return Ok(batch);
+    }
+
+    let rows = batch.num_rows();
+    let mut fields = vec![
+        Arc::new(Field::new("topic", DataType::Utf8, false)),
+        Arc::new(Field::new("time", DataType::UInt64, false)),
+    ];
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(vec![topic; rows])),
+        Arc::new(UInt64Array::from(vec![time; rows])),
+    ];
+    let schema = batch.schema();
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if field.name() != "topic" && field.name() != "time" {
+            fields.push(Arc::clone(field));
+            columns.push(Arc::clone(column));
+        }
+    }
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+}
+
+/// Decode every message of an MCAP file into DataPoints, in file order
+pub fn read_datapoints(bytes: &[u8]) -> Result<Vec<DataPoint>, ArrowError> {
+    let records = bytes.strip_prefix(MAGIC)
+        .ok_or_else(|| parse_error("Not an MCAP file"))?;
+
+    let mut reader = McapReader::default();
+    if !reader.read_records(records.strip_suffix(MAGIC).unwrap_or(records))? {
+        warn!("MCAP file has no data end record, it may be truncated");
+    }
+    for (encoding, count) in &reader.skipped {
+        warn!("Skipped {} MCAP messages with unsupported encoding {:?}", count, encoding);
+    }
+    Ok(reader.datapoints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow_serde::Shape;
+    use crate::ws_handler::arrow_batch;
+    use arrow::compute::concat_batches;
+
+    fn batch(topic: &str, time: u64, data: Value) -> RecordBatch {
+        arrow_batch(topic, time, &data, &Shape::Infer).unwrap()
+    }
+
+    fn rows(datapoints: &[DataPoint]) -> Vec<(String, u64, Value)> {
+        datapoints.iter()
+            .map(|datapoint| (datapoint.topic.clone(), datapoint.time, datapoint.to_data().unwrap()))
+            .collect()
+    }
+
+    fn row(topic: &str, time: u64, data: Value) -> (String, u64, Value) {
+        (topic.to_string(), time, data)
+    }
+
+    fn write(encoding: McapEncoding, batches: &[(&str, RecordBatch)], finish: bool) -> Vec<u8> {
+        let mut writer = McapWriter::new(Vec::new(), encoding).unwrap();
+        for (topic, batch) in batches {
+            writer.write(topic, batch).unwrap();
+        }
+        if finish {
+            writer.finish().unwrap();
+        }
+        assert_eq!(writer.position() as usize, writer.output.len());
+        writer.output
+    }
+
+    #[test]
+    fn json_files_round_trip() {
+        let bytes = write(McapEncoding::Json, &[
+            ("robot", batch("robot", 1000, json!({ "pose": { "x": 1.5, "y": 2 }, "mode": "idle" }))),
+            ("battery", batch("battery", 1005, json!({ "level": 0.5 }))),
+            ("robot", batch("robot", 1010, json!({ "pose": { "x": 1.75, "y": 3 }, "mode": "driving" }))),
+        ], true);
+        assert!(bytes.starts_with(MAGIC) && bytes.ends_with(MAGIC));
+
+        assert_eq!(rows(&read_datapoints(&bytes).unwrap()), [
+            row("robot", 1000, json!({ "pose": { "x": 1.5, "y": 2 }, "mode": "idle" })),
+            row("battery", 1005, json!({ "level": 0.5 })),
+            row("robot", 1010, json!({ "pose": { "x": 1.75, "y": 3 }, "mode": "driving" })),
+        ]);
+    }
+
+    #[test]
+    fn arrow_files_round_trip_multi_row_batches() {
+        let first = batch("robot", 1000, json!({ "x": 1, "ok": true }));
+        let second = batch("robot", 1010, json!({ "x": 2, "ok": false }));
+        let both = concat_batches(&first.schema(), [&first, &second]).unwrap();
+        let bytes = write(McapEncoding::Arrow, &[
+            ("robot", both),
+            ("robot", batch("robot", 1020, json!({ "x": 3, "ok": true }))),
+        ], true);
+
+        let datapoints = read_datapoints(&bytes).unwrap();
+        assert_eq!(rows(&datapoints), [
+            row("robot", 1000, json!({ "x": 1, "ok": true })),
+            row("robot", 1010, json!({ "x": 2, "ok": false })),
+            row("robot", 1020, json!({ "x": 3, "ok": true })),
+        ]);
+    }
+
+    #[test]
+    fn schema_changes_start_a_new_channel() {
+        for encoding in [McapEncoding::Json, McapEncoding::Arrow] {
+            let bytes = write(encoding, &[
+                ("robot", batch("robot", 1000, json!({ "x": 1 }))),
+                ("robot", batch("robot", 1010, json!({ "x": "one" }))),
+            ], true);
+            assert_eq!(rows(&read_datapoints(&bytes).unwrap()), [
+                row("robot", 1000, json!({ "x": 1 })),
+                row("robot", 1010, json!({ "x": "one" })),
+            ]);
+        }
+    }
+
+    #[test]
+    fn schemas_seen_before_reuse_their_channel() {
+        for encoding in [McapEncoding::Json, McapEncoding::Arrow] {
+            let mut writer = McapWriter::new(Vec::new(), encoding).unwrap();
+            for time in 0..10 {
+                let x = if time % 2 == 0 { json!(time) } else { json!(time.to_string()) };
+                writer.write("robot", &batch("robot", time, json!({ "x": x }))).unwrap();
+            }
+            assert_eq!(writer.channels["robot"].len(), 2);
+            assert_eq!(writer.next_id, 3);
+            writer.finish().unwrap();
+
+            let datapoints = read_datapoints(&writer.output).unwrap();
+            assert_eq!(rows(&datapoints[8..]), [row("robot", 8, json!({ "x": 8 })), row("robot", 9, json!({ "x": "9" }))]);
+        }
+    }
+
+    #[test]
+    fn chunk_sizes_are_checked_before_decompressing() {
+        let chunk = |compression: &str, uncompressed_size: u64, records: &[u8]| {
+            let chunk = RecordBuilder::default()
+                .u64(0)
+                .u64(0)
+                .u64(uncompressed_size)
+                .u32(0)
+                .string(compression)
+                .u64(records.len() as u64)
+                .rest(records)
+                .finish(OP_CHUNK);
+            [MAGIC, &chunk].concat()
+        };
+        let compressed = zstd::bulk::compress(&[0; 64], 3).unwrap();
+
+        // Huge sizes are refused without allocating them
+        for size in [u64::MAX, 1 << 40, (MAX_CHUNK_BYTES + 1) as u64, (compressed.len() * MAX_COMPRESSION_RATIO + 1) as u64] {
+            let error = read_datapoints(&chunk("zstd", size, &compressed)).unwrap_err().to_string();
+            assert!(error.contains("at most"), "{}", error);
+        }
+        // Sizes that don't match the records are corrupt too
+        assert!(read_datapoints(&chunk("zstd", 32, &compressed)).is_err());
+        assert!(read_datapoints(&chunk("", 5, b"abc")).is_err());
+        assert!(read_datapoints(&chunk("zstd", 64, &compressed)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn unfinished_files_read_up_to_the_last_whole_record() {
+        let bytes = write(McapEncoding::Json, &[
+            ("robot", batch("robot", 1000, json!({ "x": 1 }))),
+            ("robot", batch("robot", 1010, json!({ "x": 2 }))),
+        ], false);
+        assert_eq!(read_datapoints(&bytes).unwrap().len(), 2);
+        assert_eq!(rows(&read_datapoints(&bytes[..bytes.len() - 3]).unwrap()), [row("robot", 1000, json!({ "x": 1 }))]);
+    }
+
+    #[test]
+    fn times_past_the_nanosecond_range_saturate() {
+        let bytes = write(McapEncoding::Json, &[("robot", batch("robot", u64::MAX, json!({ "x": 1 })))], true);
+        assert_eq!(read_datapoints(&bytes).unwrap()[0].time, u64::MAX / NANOS_PER_MS);
+    }
+
+    /// `testdata/spec.mcap` is assembled by `testdata/make_spec_mcap.py`
+    /// straight from the spec, with CRCs, message and chunk indexes, a summary
+    /// and a zstd and an lz4 chunk compressed by the reference command line
+    /// tools. Its `/camera` channel is CDR, which isn't supported.
+    #[test]
+    fn reads_a_spec_assembled_file() {
+        let datapoints = read_datapoints(include_bytes!("../testdata/spec.mcap")).unwrap();
+        assert_eq!(rows(&datapoints), [
+            row("/robot/pose", 1000, json!({ "x": 1.5, "y": -2.0 })),
+            row("/robot/pose", 1100, json!({ "x": 1.75, "y": -1.5 })),
+            row("/battery", 1050, json!({ "value": 12.5 })),
+        ]);
+    }
+
+    #[test]
+    fn rejects_files_without_the_magic() {
+        assert!(read_datapoints(b"PAR1").is_err());
+        assert!(read_datapoints(b"").is_err());
+    }
+}
@@ -0,0 +1,620 @@
+//! Recording of everything published on the bridge.
+//!
+//! A session directory holds the recorded files and a `manifest.json` listing
+//! them per topic. In the `arrow` format every topic gets its own rolling
+//! series of Arrow IPC stream files, in the `mcap` format all topics share a
+//! rolling series of MCAP files:
+//!
+//! ```text
+//! <dir>/session-<unix ms>/            <dir>/session-<unix ms>/
+//!     manifest.json                       manifest.json
+//!     robot_0/pose/000000.arrows          000000.mcap
+//!     robot_0/pose/000001.arrows          000001.mcap
+//!     test/topic/000000.arrows
+//! ```
+//!
+//! Rows are written with the flattened `topic`, `time` and data columns the
+//! bridge sends, so any file can be fed back through `--replay`. Files stay
+//! readable up to the last flush even if the bridge is killed. A file is
+//! replaced by a new one when it grows past the size or age limit, and an
+//! Arrow file also when its topic's schema changes.
+//!
+//! The recorder runs on its own thread, so file I/O never blocks the async
+//! runtime, and is fed by an unbounded channel rather than the bus, so falling
+//! behind delays the recording instead of losing messages.
+
+use crate::datapoint;
+use crate::mcap::{McapEncoding, McapWriter};
+use crate::state::{BusMessage, Payload, StateHandle};
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatch;
+use log::{error, info};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// How often files are flushed and the manifest rewritten
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RecordFormat {
+    /// Arrow IPC stream files, one series per topic
+    Arrow,
+    /// MCAP files shared by every topic
+    Mcap,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecordOptions {
+    pub format: RecordFormat,
+    /// Message encoding of `mcap` recordings
+    pub mcap_encoding: McapEncoding,
+    /// Start a new file once the current one reaches this size
+    pub max_file_bytes: u64,
+    /// Start a new file once the current one has been open this long
+    pub max_file_age: Duration,
+}
+
+/// Contents of `manifest.json`
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct Manifest {
+    /// `arrow_ipc_stream` or `mcap`
+    pub format: String,
+    /// Wall clock in ms since the Unix epoch
+    pub started_at_ms: u64,
+    /// Set once the bridge shut down cleanly
+    pub ended_at_ms: Option<u64>,
+    /// Files in the order they were written, per topic
+    pub topics: BTreeMap<String, Vec<RecordedFile>>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RecordedFile {
+    /// Relative to the session directory
+    pub path: String,
+    /// Earliest and latest row time in ms, `None` until a row is written
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub rows: usize,
+    /// Size of the file, shared by every topic in an MCAP file
+    pub bytes: u64,
+    /// `false` while the file is still being written
+    pub complete: bool,
+}
+
+/// Counts what the stream writer writes, for size based rotation
+struct CountingWriter {
+    inner: BufWriter<File>,
+    bytes: u64,
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bytes += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// The file a topic is currently written to
+struct TopicFile {
+    writer: StreamWriter<CountingWriter>,
+    schema: SchemaRef,
+    opened: Instant,
+}
+
+/// The MCAP file every topic is currently written to
+struct McapFile {
+    writer: McapWriter<BufWriter<File>>,
+    path: String,
+    opened: Instant,
+    topics: HashSet<String>,
+}
+
+pub struct Recorder {
+    session_dir: PathBuf,
+    options: RecordOptions,
+    manifest: Manifest,
+    files: HashMap<String, TopicFile>,
+    mcap: Option<McapFile>,
+    /// Next file number per directory, two topics may share one once
+    /// sanitised
+    next_file: HashMap<PathBuf, usize>,
+}
+
+fn unix_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// Directory for a topic's files, one level per `/` separated part with
+/// anything unsafe in a file name replaced by `_`
+fn topic_dir(topic: &str) -> PathBuf {
+    topic.split('/')
+        .map(|part| {
+            let part: String = part.chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' { c } else { '_' })
+                .collect();
+            if part.is_empty() || part.chars().all(|c| c == '.') { "_".to_string() } else { part }
+        })
+        .collect()
+}
+
+impl Recorder {
+    /// Create a new session directory under `dir`
+    pub fn create(dir: &Path, options: RecordOptions) -> std::io::Result<Self> {
+        let started_at_ms = unix_ms();
+        let session_dir = dir.join(format!("session-{}", started_at_ms));
+        std::fs::create_dir_all(dir)?;
+        std::fs::create_dir(&session_dir)?;
+
+        let recorder = Self {
+            session_dir,
+            options,
+            manifest: Manifest {
+                format: match options.format {
+                    RecordFormat::Arrow => "arrow_ipc_stream".to_string(),
+                    RecordFormat::Mcap => "mcap".to_string(),
+                },
+                started_at_ms,
+                ..Default::default()
+            },
+            files: HashMap::new(),
+            mcap: None,
+            next_file: HashMap::new(),
+        };
+        recorder.write_manifest()?;
+        Ok(recorder)
+    }
+
+    pub fn session_dir(&self) -> &Path {
+        &self.session_dir
+    }
+
+    /// Record a bus message, skipping everything that isn't data
+    pub fn record(&mut self, bus_message: &BusMessage) {
+        let batch = match &bus_message.payload {
+            Payload::Datapoint(datapoint) => datapoint.to_flattened_record_batch(),
+            Payload::Batch(batch) => Ok(batch.clone()),
+            Payload::Status(_) => return,
+        };
+
+        let result = batch.and_then(|batch| self.write(&bus_message.topic, &batch));
+        if let Err(e) = result {
+            error!("Failed to record {}: {}", bus_message.topic, e);
+            // Start over with a fresh file rather than append to a broken one.
+            // The MCAP writer converts a batch before writing any of it, so
+            // only a failed write breaks the shared file.
+            match (self.options.format, e) {
+                (RecordFormat::Arrow, _) => self.close(&bus_message.topic),
+                (RecordFormat::Mcap, ArrowError::IoError(..)) => self.close_mcap(),
+                (RecordFormat::Mcap, _) => {}
+            }
+        }
+    }
+
+    fn write(&mut self, topic: &str, batch: &RecordBatch) -> Result<(), ArrowError> {
+        let bytes = match self.options.format {
+            RecordFormat::Arrow => self.write_arrow(topic, batch)?,
+            RecordFormat::Mcap => self.write_mcap(topic, batch)?,
+        };
+
+        let range = datapoint::batch_time_range(batch).ok().flatten();
+        if let Some(entry) = self.current_entry(topic) {
+            entry.rows += batch.num_rows();
+            entry.bytes = bytes;
+            if let Some((start, end)) = range {
+                entry.start_time = Some(entry.start_time.map_or(start, |time| time.min(start)));
+                entry.end_time = Some(entry.end_time.map_or(end, |time| time.max(end)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Write to the topic's own file, returning its size
+    fn write_arrow(&mut self, topic: &str, batch: &RecordBatch) -> Result<u64, ArrowError> {
+        let needs_new_file = match self.files.get(topic) {
+            Some(file) => file.schema != batch.schema()
+                || file.writer.get_ref().bytes >= self.options.max_file_bytes
+                || file.opened.elapsed() >= self.options.max_file_age,
+            None => true,
+        };
+        if needs_new_file {
+            self.close(topic);
+            self.open(topic, batch.schema())?;
+        }
+
+        let file = self.files.get_mut(topic).expect("file was just opened");
+        file.writer.write(batch)?;
+        Ok(file.writer.get_ref().bytes)
+    }
+
+    /// Write to the shared MCAP file, returning its size
+    fn write_mcap(&mut self, topic: &str, batch: &RecordBatch) -> Result<u64, ArrowError> {
+        let needs_new_file = self.mcap.as_ref().is_none_or(|file| {
+            file.writer.position() >= self.options.max_file_bytes
+                || file.opened.elapsed() >= self.options.max_file_age
+        });
+        if needs_new_file {
+            self.close_mcap();
+            self.open_mcap()?;
+        }
+
+        let file = self.mcap.as_mut().expect("file was just opened");
+        if file.topics.insert(topic.to_string()) {
+            self.manifest.topics.entry(topic.to_string()).or_default().push(RecordedFile {
+                path: file.path.clone(),
+                ..Default::default()
+            });
+        }
+        file.writer.write(topic, batch)?;
+        Ok(file.writer.position())
+    }
+
+    fn current_entry(&mut self, topic: &str) -> Option<&mut RecordedFile> {
+        self.manifest.topics.get_mut(topic).and_then(|files| files.last_mut())
+    }
+
+    fn open(&mut self, topic: &str, schema: SchemaRef) -> Result<(), ArrowError> {
+        let dir = topic_dir(topic);
+        let index = self.next_file.entry(dir.clone()).or_default();
+        let relative = dir.join(format!("{:06}.arrows", index));
+        *index += 1;
+
+        let path = self.session_dir.join(&relative);
+        let io_error = |e: std::io::Error| ArrowError::IoError(format!("{}: {}", path.display(), e), e);
+        std::fs::create_dir_all(self.session_dir.join(&dir)).map_err(io_error)?;
+        let file = File::create(&path).map_err(io_error)?;
+
+        let output = CountingWriter { inner: BufWriter::new(file), bytes: 0 };
+        let writer = StreamWriter::try_new(output, &schema)?;
+        self.files.insert(topic.to_string(), TopicFile { writer, schema, opened: Instant::now() });
+
+        let relative = relative.components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        info!("Recording {} to {}", topic, relative);
+        self.manifest.topics.entry(topic.to_string()).or_default().push(RecordedFile {
+            path: relative,
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    fn open_mcap(&mut self) -> Result<(), ArrowError> {
+        let index = self.next_file.entry(PathBuf::new()).or_default();
+        let relative = format!("{:06}.mcap", index);
+        *index += 1;
+
+        let path = self.session_dir.join(&relative);
+        let file = File::create(&path)
+            .map_err(|e| ArrowError::IoError(format!("{}: {}", path.display(), e), e))?;
+        let writer = McapWriter::new(BufWriter::new(file), self.options.mcap_encoding)?;
+
+        info!("Recording to {}", relative);
+        self.mcap = Some(McapFile {
+            writer,
+            path: relative,
+            opened: Instant::now(),
+            topics: HashSet::new(),
+        });
+        Ok(())
+    }
+
+    /// Finish the current MCAP file, if there is one
+    fn close_mcap(&mut self) {
+        let Some(mut file) = self.mcap.take() else {
+            return;
+        };
+        if let Err(e) = file.writer.finish() {
+            error!("Failed to finish recording {}: {}", file.path, e);
+        }
+
+        let bytes = file.writer.position();
+        for topic in &file.topics {
+            if let Some(entry) = self.current_entry(topic) {
+                entry.bytes = bytes;
+                entry.complete = true;
+            }
+        }
+    }
+
+    /// Finish a topic's current file, if it has one
+    fn close(&mut self, topic: &str) {
+        let Some(mut file) = self.files.remove(topic) else {
+            return;
+        };
+        if let Err(e) = file.writer.finish().and_then(|_| Ok(file.writer.get_mut().flush()?)) {
+            error!("Failed to finish recording of {}: {}", topic, e);
+        }
+
+        let bytes = file.writer.get_ref().bytes;
+        if let Some(entry) = self.current_entry(topic) {
+            entry.bytes = bytes;
+            entry.complete = true;
+        }
+    }
+
+    /// Flush every open file to disk, closing the ones past their age, and
+    /// bring the manifest up to date
+    pub fn flush(&mut self) {
+        let expired: Vec<String> = self.files.iter()
+            .filter(|(_, file)| file.opened.elapsed() >= self.options.max_file_age)
+            .map(|(topic, _)| topic.clone())
+            .collect();
+        for topic in expired {
+            self.close(&topic);
+        }
+        if self.mcap.as_ref().is_some_and(|file| file.opened.elapsed() >= self.options.max_file_age) {
+            self.close_mcap();
+        }
+
+        for (topic, file) in &mut self.files {
+            if let Err(e) = file.writer.flush() {
+                error!("Failed to flush recording of {}: {}", topic, e);
+            }
+        }
+        if let Some(file) = &mut self.mcap
+            && let Err(e) = file.writer.flush()
+        {
+            error!("Failed to flush recording {}: {}", file.path, e);
+        }
+        if let Err(e) = self.write_manifest() {
+            error!("Failed to write recording manifest: {}", e);
+        }
+    }
+
+    /// Close every file and mark the session as ended
+    pub fn finish(mut self) {
+        let topics: Vec<String> = self.files.keys().cloned().collect();
+        for topic in topics {
+            self.close(&topic);
+        }
+        self.close_mcap();
+        self.manifest.ended_at_ms = Some(unix_ms());
+        if let Err(e) = self.write_manifest() {
+            error!("Failed to write recording manifest: {}", e);
+        }
+        info!("Recording saved to {}", self.session_dir.display());
+    }
+
+    /// Replace `manifest.json` in one step so readers never see half of it
+    fn write_manifest(&self) -> std::io::Result<()> {
+        let path = self.session_dir.join("manifest.json");
+        let temporary = self.session_dir.join("manifest.json.tmp");
+        std::fs::write(&temporary, serde_json::to_vec_pretty(&self.manifest)?)?;
+        std::fs::rename(temporary, path)
+    }
+}
+
+/// A running recorder, stopped to finish its files
+pub struct RecorderHandle {
+    state: StateHandle,
+    thread: JoinHandle<()>,
+}
+
+impl RecorderHandle {
+    /// Stop feeding the recorder and wait for it to write what it was sent
+    pub async fn stop(self) {
+        self.state.lock().unwrap().recorder = None;
+        match tokio::task::spawn_blocking(move || self.thread.join()).await {
+            Ok(Ok(())) => {}
+            Ok(Err(_)) => error!("Recorder thread panicked"),
+            Err(e) => error!("Failed to wait for the recorder: {}", e),
+        }
+    }
+}
+
+/// Record every message published on the bridge until stopped
+pub fn spawn_recorder(state: StateHandle, recorder: Recorder) -> std::io::Result<RecorderHandle> {
+    let (sender, receiver) = mpsc::channel();
+    let thread = std::thread::Builder::new()
+        .name("recorder".to_string())
+        .spawn(move || run_recorder(recorder, receiver))?;
+    state.lock().unwrap().recorder = Some(sender);
+    Ok(RecorderHandle { state, thread })
+}
+
+/// Record until every sender is gone, flushing every `FLUSH_INTERVAL`
+fn run_recorder(mut recorder: Recorder, receiver: mpsc::Receiver<std::sync::Arc<BusMessage>>) {
+    info!("Recording to {}", recorder.session_dir().display());
+    let mut next_flush = Instant::now() + FLUSH_INTERVAL;
+    loop {
+        match receiver.recv_timeout(next_flush.saturating_duration_since(Instant::now())) {
+            Ok(bus_message) => recorder.record(&bus_message),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        // Checked after messages too, a busy bus never times out
+        if Instant::now() >= next_flush {
+            recorder.flush();
+            next_flush = Instant::now() + FLUSH_INTERVAL;
+        }
+    }
+    recorder.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow_serde::Shape;
+    use crate::state::{WSBridgeState, BUS_CAPACITY};
+    use crate::test_util::TempDir;
+    use crate::ws_handler::arrow_batch;
+    use serde_json::{json, Value};
+
+    fn options(format: RecordFormat) -> RecordOptions {
+        RecordOptions {
+            format,
+            mcap_encoding: McapEncoding::Json,
+            max_file_bytes: u64::MAX,
+            max_file_age: Duration::from_secs(3600),
+        }
+    }
+
+    fn message(topic: &str, time: u64, data: Value) -> BusMessage {
+        BusMessage {
+            source: None,
+            topic: topic.to_string(),
+            payload: Payload::Batch(arrow_batch(topic, time, &data, &Shape::Infer).unwrap()),
+        }
+    }
+
+    fn read_manifest(session_dir: &Path) -> Manifest {
+        serde_json::from_slice(&std::fs::read(session_dir.join("manifest.json")).unwrap()).unwrap()
+    }
+
+    fn paths(files: &[RecordedFile]) -> Vec<&str> {
+        files.iter().map(|file| file.path.as_str()).collect()
+    }
+
+    #[test]
+    fn topic_dirs_stay_inside_the_session() {
+        let cases = [
+            ("robot_0/pose", "robot_0/pose"),
+            ("../../etc/passwd", "_/_/etc/passwd"),
+            ("/abs//x", "_/abs/_/x"),
+            ("a b:c*?\\d", "a_b_c___d"),
+            (".", "_"),
+            ("...", "_"),
+            ("v1.2/ünï", "v1.2/_n_"),
+            ("", "_"),
+        ];
+        for (topic, expected) in cases {
+            let dir = topic_dir(topic);
+            assert_eq!(dir, PathBuf::from(expected), "{:?}", topic);
+            assert!(dir.components().all(|component| matches!(component, std::path::Component::Normal(_))));
+        }
+    }
+
+    #[test]
+    fn arrow_files_rotate_by_size_and_schema() {
+        let dir = TempDir::new("recorder-size");
+        let mut recorder = Recorder::create(dir.path(), RecordOptions { max_file_bytes: 1, ..options(RecordFormat::Arrow) }).unwrap();
+        let session_dir = recorder.session_dir().to_path_buf();
+        for time in 0..3 {
+            recorder.record(&message("robot/pose", time, json!({ "x": time })));
+        }
+        recorder.finish();
+
+        let manifest = read_manifest(&session_dir);
+        let files = &manifest.topics["robot/pose"];
+        assert_eq!(paths(files), ["robot/pose/000000.arrows", "robot/pose/000001.arrows", "robot/pose/000002.arrows"]);
+        for (time, file) in files.iter().enumerate() {
+            let bytes = std::fs::read(session_dir.join(&file.path)).unwrap();
+            assert_eq!((file.rows, file.bytes, file.complete), (1, bytes.len() as u64, true));
+            assert_eq!((file.start_time, file.end_time), (Some(time as u64), Some(time as u64)));
+            let datapoints = datapoint::DataPoint::from_ipc_bytes(&bytes).unwrap();
+            assert_eq!(datapoints[0].to_data::<Value>().unwrap(), json!({ "x": time }));
+        }
+
+        let dir = TempDir::new("recorder-schema");
+        let mut recorder = Recorder::create(dir.path(), options(RecordFormat::Arrow)).unwrap();
+        let session_dir = recorder.session_dir().to_path_buf();
+        recorder.record(&message("robot", 0, json!({ "x": 1 })));
+        recorder.record(&message("robot", 1, json!({ "x": 2 })));
+        recorder.record(&message("robot", 2, json!({ "x": "three" })));
+        recorder.finish();
+        let files = &read_manifest(&session_dir).topics["robot"];
+        assert_eq!(paths(files), ["robot/000000.arrows", "robot/000001.arrows"]);
+        assert_eq!((files[0].rows, files[1].rows), (2, 1));
+    }
+
+    #[test]
+    fn files_rotate_by_age() {
+        for format in [RecordFormat::Arrow, RecordFormat::Mcap] {
+            let dir = TempDir::new(&format!("recorder-age-{:?}", format));
+            let age = Duration::from_millis(50);
+            let mut recorder = Recorder::create(dir.path(), RecordOptions { max_file_age: age, ..options(format) }).unwrap();
+            let session_dir = recorder.session_dir().to_path_buf();
+            recorder.record(&message("robot", 0, json!({ "x": 1 })));
+            recorder.record(&message("robot", 1, json!({ "x": 2 })));
+            std::thread::sleep(age);
+            recorder.record(&message("robot", 2, json!({ "x": 3 })));
+            // Flushing closes files past their age even without new rows
+            std::thread::sleep(age);
+            recorder.flush();
+
+            let files = &read_manifest(&session_dir).topics["robot"];
+            let rows: Vec<(usize, bool)> = files.iter().map(|file| (file.rows, file.complete)).collect();
+            assert_eq!(rows, [(2, true), (1, true)], "{:?}", format);
+            recorder.finish();
+        }
+    }
+
+    #[test]
+    fn manifest_tracks_every_topic_and_the_session() {
+        let dir = TempDir::new("recorder-manifest");
+        let mut recorder = Recorder::create(dir.path(), options(RecordFormat::Mcap)).unwrap();
+        let session_dir = recorder.session_dir().to_path_buf();
+        assert!(session_dir.file_name().unwrap().to_string_lossy().starts_with("session-"));
+
+        recorder.record(&message("robot", 200, json!({ "x": 1 })));
+        recorder.record(&message("battery", 150, json!({ "level": 0.5 })));
+        recorder.record(&message("robot", 100, json!({ "x": 2 })));
+        recorder.record(&BusMessage {
+            source: None,
+            topic: String::new(),
+            payload: Payload::Status(crate::replay::PlaybackStatus {
+                playing: true,
+                time: 0,
+                speed: 1.0,
+                range: (0, 0),
+                loop_range: None,
+            }),
+        });
+        recorder.flush();
+
+        let open = read_manifest(&session_dir);
+        assert_eq!((open.format.as_str(), open.ended_at_ms), ("mcap", None));
+        assert!(open.topics.values().flatten().all(|file| !file.complete));
+
+        recorder.finish();
+        let finished = read_manifest(&session_dir);
+        assert!(finished.ended_at_ms.is_some_and(|ended| ended >= finished.started_at_ms));
+        assert_eq!(finished.topics.keys().collect::<Vec<_>>(), ["battery", "robot"]);
+        let size = std::fs::metadata(session_dir.join("000000.mcap")).unwrap().len();
+        let robot = &finished.topics["robot"][0];
+        assert_eq!(robot.path, "000000.mcap");
+        assert_eq!((robot.rows, robot.start_time, robot.end_time), (2, Some(100), Some(200)));
+        assert_eq!((robot.bytes, robot.complete), (size, true));
+        let battery = &finished.topics["battery"][0];
+        assert_eq!((battery.rows, battery.start_time, battery.bytes), (1, Some(150), size));
+        assert!(!session_dir.join("manifest.json.tmp").exists());
+    }
+
+    #[tokio::test]
+    async fn spawned_recorders_keep_up_with_bursts() {
+        let dir = TempDir::new("recorder-burst");
+        let recorder = Recorder::create(dir.path(), options(RecordFormat::Arrow)).unwrap();
+        let session_dir = recorder.session_dir().to_path_buf();
+        let state = WSBridgeState::new().into_handle();
+        let handle = spawn_recorder(state.clone(), recorder).unwrap();
+
+        // Far more than a bus receiver buffers, published without yielding
+        let total = 3 * BUS_CAPACITY as u64;
+        {
+            let mut state = state.lock().unwrap();
+            for time in 0..total {
+                let message = message("robot", time, json!({ "x": time }));
+                state.publish(None, &message.topic, time, message.payload);
+            }
+        }
+        handle.stop().await;
+        assert!(state.lock().unwrap().recorder.is_none());
+
+        let files = &read_manifest(&session_dir).topics["robot"];
+        assert_eq!(files.iter().map(|file| file.rows as u64).sum::<u64>(), total);
+        assert_eq!(files.last().unwrap().end_time, Some(total - 1));
+    }
+}
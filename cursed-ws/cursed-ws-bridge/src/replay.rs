@@ -0,0 +1,597 @@
+//! Replay of recorded data through the bridge.
+//!
+//! Three formats are understood:
+//!
+//! - CSV rows of `time,key,value`, the layout `CursedCore::from_csv` reads.
+//!   `time` is in ms, `key` becomes the topic and `value` a `value` column,
+//!   numeric when it parses as one. `time,key,value` header rows are skipped
+//!   wherever they are, so concatenated files load too.
+//! - MCAP files, see `crate::mcap`. Each channel is replayed on its topic at
+//!   the messages' log time.
+//! - Arrow IPC files or streams with `topic` and `time` columns, such as the
+//!   ones the bridge sends. Every row becomes one `DataPoint`.
+//!
+//! Rows are published on their original topic and timestamp, paced by the
+//! gaps between timestamps divided by the playback speed. Clients control the
+//! playback with `PlaybackCommand`s and every client is kept in sync with
+//! `PlaybackStatus` messages.
+
+use crate::datapoint::DataPoint;
+use crate::mcap;
+use crate::state::{Payload, StateHandle};
+use crate::ws_handler::SyncUpdate;
+use arrow::error::ArrowError;
+use log::{debug, error, info, warn};
+use serde_json::Value;
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, MissedTickBehavior};
+
+/// Read every row of a CSV, MCAP or Arrow recording, sorted by time
+pub fn load(path: &Path) -> Result<Vec<DataPoint>, Box<dyn std::error::Error>> {
+    let has_extension = |expected: &str| path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case(expected));
+
+    let mut datapoints = if has_extension("csv") {
+        load_csv(path)?
+    } else if has_extension("mcap") {
+        mcap::read_datapoints(&std::fs::read(path)?)?
+    } else {
+        DataPoint::from_ipc_bytes(&std::fs::read(path)?)?
+    };
+
+    // Stable, so rows sharing a timestamp keep their recorded order
+    datapoints.sort_by_key(|datapoint| datapoint.time);
+    Ok(datapoints)
+}
+
+fn load_csv(path: &Path) -> Result<Vec<DataPoint>, Box<dyn std::error::Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_path(path)?;
+
+    let mut datapoints = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record?;
+        let [time, key, value] = [0, 1, 2].map(|column| record.get(column).unwrap_or_default().trim());
+
+        let time = match time.parse::<u64>() {
+            Ok(time) => time,
+            Err(_) if time == "time" => continue,
+            Err(e) => {
+                let line = record.position().map_or(index as u64 + 1, |position| position.line());
+                return Err(format!("Line {}: bad time {:?}: {}", line, time, e).into());
+            }
+        };
+
+        let value = match value.parse::<f64>() {
+            Ok(number) => Value::from(number),
+            Err(_) => Value::from(value),
+        };
+        let data = serde_json::json!({ "value": value });
+        datapoints.push(DataPoint::from_data(key.to_string(), time, &data)?);
+    }
+    Ok(datapoints)
+}
+
+fn payload(datapoint: &DataPoint, use_arrow_ipc: bool) -> Result<Payload, ArrowError> {
+    if use_arrow_ipc {
+        return Ok(Payload::Batch(datapoint.to_flattened_record_batch()?));
+    }
+
+    let data: Value = datapoint.to_data()?;
+    let sync_update = SyncUpdate::new(
+        datapoint.topic.clone(),
+        vec![(datapoint.time, data.to_string())],
+    );
+    Ok(Payload::Datapoint(sync_update.datapoint(datapoint.time)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayOptions {
+    /// Playback speed, `2.0` plays twice as fast as recorded
+    pub speed: f64,
+    /// Start over from the first row after the last one
+    pub looped: bool,
+}
+
+/// Sent by a client as `WSMessage::PlaybackControl` to drive the replay
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+pub enum PlaybackCommand {
+    Play,
+    Pause,
+    /// Jump to a recording time in ms
+    Seek { time: u64 },
+    SetSpeed { speed: f64 },
+    /// Repeat `[start, end]` in ms, `None` plays the whole recording again
+    Loop { range: Option<(u64, u64)> },
+    /// Pause and publish the next row only
+    Step,
+}
+
+/// Broadcast to every client as `WSMessage::PlaybackStatus` whenever the
+/// playback changes, and periodically while it plays
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+pub struct PlaybackStatus {
+    pub playing: bool,
+    /// Current recording time in ms
+    pub time: u64,
+    pub speed: f64,
+    /// First and last timestamp of the recording
+    pub range: (u64, u64),
+    pub loop_range: Option<(u64, u64)>,
+}
+
+/// How often a status goes out while playing
+const STATUS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Replay position and transport state
+struct Player {
+    datapoints: Vec<DataPoint>,
+    /// Next row to publish
+    index: usize,
+    /// Recording time in ms when paused, or when `anchor` was taken
+    position: f64,
+    /// Wall time `position` was reached at while playing
+    anchor: Option<Instant>,
+    speed: f64,
+    looped: bool,
+    loop_range: Option<(u64, u64)>,
+}
+
+impl Player {
+    fn first_time(&self) -> u64 {
+        self.datapoints.first().map_or(0, |datapoint| datapoint.time)
+    }
+
+    fn last_time(&self) -> u64 {
+        self.datapoints.last().map_or(0, |datapoint| datapoint.time)
+    }
+
+    fn bounds(&self) -> (u64, u64) {
+        self.loop_range.unwrap_or((self.first_time(), self.last_time()))
+    }
+
+    /// Recording time at `now`, advancing with the wall clock while playing
+    fn position_at(&self, now: Instant) -> f64 {
+        match self.anchor {
+            Some(anchor) => self.position + (now - anchor).as_secs_f64() * 1000.0 * self.speed,
+            None => self.position,
+        }
+    }
+
+    /// Keep the current position but measure from `now` from here on
+    fn reanchor(&mut self, now: Instant) {
+        self.position = self.position_at(now);
+        if self.anchor.is_some() {
+            self.anchor = Some(now);
+        }
+    }
+
+    fn seek(&mut self, time: u64, now: Instant) {
+        let time = time.clamp(self.first_time(), self.last_time());
+        self.index = self.datapoints.partition_point(|datapoint| datapoint.time < time);
+        self.position = time as f64;
+        if self.anchor.is_some() {
+            self.anchor = Some(now);
+        }
+    }
+
+    fn status(&self, now: Instant) -> PlaybackStatus {
+        PlaybackStatus {
+            playing: self.anchor.is_some(),
+            time: self.position_at(now) as u64,
+            speed: self.speed,
+            range: (self.first_time(), self.last_time()),
+            loop_range: self.loop_range,
+        }
+    }
+
+    /// When the next row, or the end of the range, is due while playing
+    fn next_deadline(&self) -> Option<Instant> {
+        let anchor = self.anchor?;
+        let end = self.bounds().1;
+        let target = self.datapoints.get(self.index)
+            .map_or(end, |datapoint| datapoint.time.min(end));
+        let offset_ms = (target as f64 - self.position).max(0.0) / self.speed;
+        Some(anchor + Duration::from_secs_f64(offset_ms / 1000.0))
+    }
+
+    /// Take every row that is due at `now`, wrapping or stopping at the end
+    /// of the range
+    fn take_due(&mut self, now: Instant) -> Vec<DataPoint> {
+        let mut due = Vec::new();
+        let (start, end) = self.bounds();
+        let position = self.position_at(now);
+
+        while let Some(datapoint) = self.datapoints.get(self.index) {
+            if datapoint.time as f64 > position || datapoint.time > end {
+                break;
+            }
+            due.push(datapoint.clone());
+            self.index += 1;
+        }
+
+        let finished = self.datapoints.get(self.index)
+            .is_none_or(|datapoint| datapoint.time > end);
+        if finished && position >= end as f64 {
+            // A zero-length range would wrap without ever waiting
+            if (self.looped || self.loop_range.is_some()) && end > start {
+                self.seek(start, now);
+            } else {
+                info!("Replay finished");
+                self.position = end as f64;
+                self.anchor = None;
+            }
+        }
+        due
+    }
+
+    fn handle(&mut self, command: PlaybackCommand, now: Instant) -> Vec<DataPoint> {
+        match command {
+            PlaybackCommand::Play => {
+                if self.anchor.is_none() {
+                    // Playing from the end starts over
+                    if self.index >= self.datapoints.len() || self.position >= self.bounds().1 as f64 {
+                        self.seek(self.bounds().0, now);
+                    }
+                    self.anchor = Some(now);
+                }
+            }
+            PlaybackCommand::Pause => {
+                self.reanchor(now);
+                self.anchor = None;
+            }
+            PlaybackCommand::Seek { time } => self.seek(time, now),
+            PlaybackCommand::SetSpeed { speed } => {
+                if speed.is_finite() && speed > 0.0 {
+                    self.reanchor(now);
+                    self.speed = speed;
+                } else {
+                    warn!("Ignoring playback speed {}", speed);
+                }
+            }
+            PlaybackCommand::Loop { range } => {
+                match range {
+                    Some((start, end)) if start > end => warn!("Ignoring empty loop range {:?}", range),
+                    _ => self.loop_range = range,
+                }
+            }
+            PlaybackCommand::Step => {
+                self.reanchor(now);
+                self.anchor = None;
+                if let Some(datapoint) = self.datapoints.get(self.index) {
+                    self.index += 1;
+                    self.position = datapoint.time as f64;
+                    return vec![datapoint.clone()];
+                }
+            }
+        }
+        Vec::new()
+    }
+}
+
+fn publish(state: &StateHandle, datapoints: &[DataPoint], use_arrow_ipc: bool) {
+    if datapoints.is_empty() {
+        return;
+    }
+
+    let mut state = state.lock().unwrap();
+    for datapoint in datapoints {
+        match payload(datapoint, use_arrow_ipc) {
+            Ok(payload) => {
+                let dt_ms = datapoint.time.saturating_sub(state.t_ms);
+                state.tick(dt_ms);
+                state.update_last_t();
+                state.publish(None, &datapoint.topic, datapoint.time, payload);
+            }
+            Err(e) => error!("Skipping replayed row on {}: {}", datapoint.topic, e),
+        }
+    }
+}
+
+/// Publish a recording with its original pacing, driven by the playback
+/// commands clients send
+pub async fn run_replay(state: StateHandle, datapoints: Vec<DataPoint>, options: ReplayOptions) {
+    if datapoints.is_empty() {
+        info!("Nothing to replay");
+        return;
+    }
+
+    let (commands_tx, mut commands_rx) = mpsc::channel(64);
+    let use_arrow_ipc = {
+        let mut state = state.lock().unwrap();
+        state.playback = Some(commands_tx);
+        state.use_arrow_ipc
+    };
+
+    let now = Instant::now();
+    let mut player = Player {
+        position: datapoints[0].time as f64,
+        datapoints,
+        index: 0,
+        anchor: Some(now),
+        speed: options.speed,
+        looped: options.looped,
+        loop_range: None,
+    };
+    info!(
+        "Replaying {} rows from {} to {} ms at {}x",
+        player.datapoints.len(), player.first_time(), player.last_time(), player.speed
+    );
+    state.lock().unwrap().broadcast_status(player.status(now));
+
+    let mut status_timer = tokio::time::interval(STATUS_INTERVAL);
+    status_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        // Far enough out to never fire while paused
+        let deadline = player.next_deadline()
+            .unwrap_or_else(|| Instant::now() + Duration::from_secs(3600));
+
+        tokio::select! {
+            command = commands_rx.recv() => {
+                let Some(command) = command else {
+                    return;
+                };
+                debug!("Playback command {:?}", command);
+                let now = Instant::now();
+                let stepped = player.handle(command, now);
+                publish(&state, &stepped, use_arrow_ipc);
+                state.lock().unwrap().broadcast_status(player.status(now));
+            }
+            _ = tokio::time::sleep_until(deadline) => {
+                let was_playing = player.anchor.is_some();
+                let now = Instant::now();
+                let due = player.take_due(now);
+                publish(&state, &due, use_arrow_ipc);
+                if was_playing && player.anchor.is_none() {
+                    state.lock().unwrap().broadcast_status(player.status(now));
+                }
+            }
+            _ = status_timer.tick() => {
+                if player.anchor.is_some() {
+                    state.lock().unwrap().broadcast_status(player.status(Instant::now()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow_serde::Shape;
+    use crate::test_util::TempDir;
+    use crate::ws_handler::arrow_batch;
+    use arrow::ipc::writer::FileWriter;
+    use serde_json::json;
+
+    fn rows(datapoints: &[DataPoint]) -> Vec<(String, u64, Value)> {
+        datapoints.iter()
+            .map(|datapoint| (datapoint.topic.clone(), datapoint.time, datapoint.to_data().unwrap()))
+            .collect()
+    }
+
+    fn row(topic: &str, time: u64, data: Value) -> (String, u64, Value) {
+        (topic.to_string(), time, data)
+    }
+
+    #[test]
+    fn csv_rows_load_sorted_by_time() {
+        let dir = TempDir::new("replay-csv");
+        let path = dir.path().join("log.CSV");
+        std::fs::write(&path, "time,key,value\n\
+            200,robot/speed,1.5\n\
+            100,robot/mode, drive \n\
+            200,robot/speed,2\n\
+            time,key,value\n\
+            150,battery,-3e2\n").unwrap();
+
+        assert_eq!(rows(&load(&path).unwrap()), [
+            row("robot/mode", 100, json!({ "value": "drive" })),
+            row("battery", 150, json!({ "value": -300.0 })),
+            row("robot/speed", 200, json!({ "value": 1.5 })),
+            row("robot/speed", 200, json!({ "value": 2.0 })),
+        ]);
+    }
+
+    #[test]
+    fn malformed_csv_rows_name_their_line() {
+        let dir = TempDir::new("replay-bad-csv");
+        let path = dir.path().join("log.csv");
+        std::fs::write(&path, "time,key,value\n100,a,1\nsoon,a,2\n").unwrap();
+        let error = load(&path).unwrap_err().to_string();
+        assert!(error.starts_with("Line 3: bad time \"soon\""), "{}", error);
+    }
+
+    #[test]
+    fn arrow_files_load_every_row() {
+        let batches = [
+            arrow_batch("robot", 300, &json!({ "pose": { "x": 1.5 }, "ok": true }), &Shape::Infer).unwrap(),
+            arrow_batch("robot", 100, &json!({ "pose": { "x": 0.5 }, "ok": false }), &Shape::Infer).unwrap(),
+        ];
+        let mut writer = FileWriter::try_new(Vec::new(), &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+
+        let dir = TempDir::new("replay-arrow");
+        let path = dir.path().join("log.arrow");
+        std::fs::write(&path, bytes).unwrap();
+        assert_eq!(rows(&load(&path).unwrap()), [
+            row("robot", 100, json!({ "pose": { "x": 0.5 }, "ok": false })),
+            row("robot", 300, json!({ "pose": { "x": 1.5 }, "ok": true })),
+        ]);
+
+        std::fs::write(&path, b"not arrow").unwrap();
+        assert!(load(&path).is_err());
+    }
+
+    #[test]
+    fn payloads_match_the_connection_encoding() {
+        let datapoint = DataPoint::from_data("robot".to_string(), 100, &json!({ "value": 1.5 })).unwrap();
+
+        let Payload::Batch(batch) = payload(&datapoint, true).unwrap() else {
+            panic!("expected a batch");
+        };
+        assert_eq!(rows(&DataPoint::from_record_batch(&batch).unwrap()), [row("robot", 100, json!({ "value": 1.5 }))]);
+
+        let Payload::Datapoint(json) = payload(&datapoint, false).unwrap() else {
+            panic!("expected a datapoint");
+        };
+        assert_eq!((json.topic.as_str(), json.time), ("robot", 100));
+        // A `SyncUpdate` of `[time, data]` pairs
+        let data: Value = serde_json::from_str(json.data_json.as_deref().unwrap()).unwrap();
+        assert_eq!(data, json!([[100, "{\"value\":1.5}"]]));
+    }
+
+    /// Rows at 0, 100, ..., 900 ms, playing from the first one at `now`
+    fn player(now: Instant, looped: bool) -> Player {
+        let datapoints = (0..10)
+            .map(|index| DataPoint::with_json("t".to_string(), index * 100, index.to_string()))
+            .collect();
+        Player {
+            datapoints,
+            index: 0,
+            position: 0.0,
+            anchor: Some(now),
+            speed: 1.0,
+            looped,
+            loop_range: None,
+        }
+    }
+
+    fn times(datapoints: &[DataPoint]) -> Vec<u64> {
+        datapoints.iter().map(|datapoint| datapoint.time).collect()
+    }
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn rows_are_due_at_their_recorded_time() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        assert_eq!(player.next_deadline(), Some(now));
+        assert_eq!(times(&player.take_due(now)), [0]);
+        assert_eq!(player.next_deadline(), Some(now + ms(100)));
+        assert!(player.take_due(now + ms(50)).is_empty());
+        assert_eq!(times(&player.take_due(now + ms(250))), [100, 200]);
+    }
+
+    #[test]
+    fn speed_scales_the_gaps() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        player.take_due(now);
+        player.handle(PlaybackCommand::SetSpeed { speed: 2.0 }, now);
+        assert_eq!(player.next_deadline(), Some(now + ms(50)));
+        assert_eq!(times(&player.take_due(now + ms(100))), [100, 200]);
+
+        // Nonsense speeds are ignored
+        player.handle(PlaybackCommand::SetSpeed { speed: 0.0 }, now);
+        player.handle(PlaybackCommand::SetSpeed { speed: f64::NAN }, now);
+        assert_eq!(player.speed, 2.0);
+    }
+
+    #[test]
+    fn pause_holds_the_position() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        player.take_due(now + ms(150));
+        player.handle(PlaybackCommand::Pause, now + ms(150));
+
+        let later = now + ms(10_000);
+        assert_eq!(player.next_deadline(), None);
+        assert!(player.take_due(later).is_empty());
+        let status = player.status(later);
+        assert!(!status.playing);
+        assert_eq!(status.time, 150);
+
+        // Resuming carries on from where it paused
+        player.handle(PlaybackCommand::Play, later);
+        assert_eq!(player.next_deadline(), Some(later + ms(50)));
+        assert_eq!(times(&player.take_due(later + ms(50))), [200]);
+    }
+
+    #[test]
+    fn seek_jumps_and_clamps_to_the_recording() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        player.handle(PlaybackCommand::Seek { time: 450 }, now);
+        assert_eq!(player.status(now).time, 450);
+        assert_eq!(times(&player.take_due(now + ms(100))), [500]);
+
+        // Backwards replays rows again
+        player.handle(PlaybackCommand::Seek { time: 100 }, now + ms(100));
+        assert_eq!(times(&player.take_due(now + ms(100))), [100]);
+
+        player.handle(PlaybackCommand::Seek { time: 5000 }, now);
+        assert_eq!(player.status(now).time, 900);
+    }
+
+    #[test]
+    fn seek_while_paused_stays_paused() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        player.handle(PlaybackCommand::Pause, now);
+        player.handle(PlaybackCommand::Seek { time: 300 }, now);
+        assert_eq!(player.next_deadline(), None);
+        assert_eq!(player.status(now + ms(1000)).time, 300);
+    }
+
+    #[test]
+    fn step_publishes_one_row_and_pauses() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        player.take_due(now);
+        assert_eq!(times(&player.handle(PlaybackCommand::Step, now)), [100]);
+        assert_eq!(times(&player.handle(PlaybackCommand::Step, now)), [200]);
+        let status = player.status(now + ms(1000));
+        assert!(!status.playing);
+        assert_eq!(status.time, 200);
+    }
+
+    #[test]
+    fn playback_stops_at_the_end_and_play_starts_over() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        assert_eq!(player.take_due(now + ms(5000)).len(), 10);
+        assert_eq!(player.next_deadline(), None);
+        assert_eq!(player.status(now + ms(5000)).time, 900);
+
+        player.handle(PlaybackCommand::Play, now + ms(5000));
+        assert_eq!(times(&player.take_due(now + ms(5000))), [0]);
+    }
+
+    #[test]
+    fn looped_playback_wraps_to_the_start() {
+        let now = Instant::now();
+        let mut player = player(now, true);
+        assert_eq!(player.take_due(now + ms(900)).len(), 10);
+        assert!(player.anchor.is_some());
+        assert_eq!(player.status(now + ms(900)).time, 0);
+        assert_eq!(times(&player.take_due(now + ms(1000))), [0, 100]);
+    }
+
+    #[test]
+    fn loop_range_repeats_part_of_the_recording() {
+        let now = Instant::now();
+        let mut player = player(now, false);
+        player.handle(PlaybackCommand::Loop { range: Some((200, 400)) }, now);
+        player.handle(PlaybackCommand::Seek { time: 200 }, now);
+        assert_eq!(times(&player.take_due(now + ms(200))), [200, 300, 400]);
+        assert_eq!(player.status(now + ms(200)).time, 200);
+        assert_eq!(times(&player.take_due(now + ms(300))), [200, 300]);
+
+        // Backwards ranges are ignored, `None` clears the range
+        player.handle(PlaybackCommand::Loop { range: Some((400, 200)) }, now);
+        assert_eq!(player.loop_range, Some((200, 400)));
+        player.handle(PlaybackCommand::Loop { range: None }, now);
+        assert_eq!(player.bounds(), (0, 900));
+    }
+}
@@ -1,22 +1,88 @@
-use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::batcher::FlushPolicy;
+use crate::datapoint::DataPoint;
+use crate::history::History;
+use crate::replay::{PlaybackCommand, PlaybackStatus};
+use arrow::record_batch::RecordBatch;
+use tokio::sync::{broadcast, mpsc};
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize)]
+pub type ClientId = usize;
+
+/// Messages buffered on the bus before slow connections start lagging
+pub const BUS_CAPACITY: usize = 4096;
+
+/// Data carried on the bus, encoded for the wire by each connection
+#[derive(Debug, Clone)]
+pub enum Payload{
+    /// Sent to clients as a JSON `WSMessage::NewDatapoint`
+    Datapoint(DataPoint),
+    /// Sent to clients as Arrow IPC stream frames
+    Batch(RecordBatch),
+    /// Sent to every client as a JSON `WSMessage::PlaybackStatus`, whatever
+    /// their subscription
+    Status(PlaybackStatus),
+}
+
+impl Payload{
+    /// Approximate memory held by the payload, used to bound history buffers
+    pub fn size_bytes(&self) -> usize{
+        match self{
+            Payload::Datapoint(datapoint) => {
+                datapoint.topic.len()
+                    + datapoint.data_json.as_ref().map_or(0, |json| json.len())
+                    + datapoint.arrow_data.as_ref().map_or(0, |data| data.get_array_memory_size())
+            }
+            Payload::Batch(batch) => batch.get_array_memory_size(),
+            Payload::Status(_) => std::mem::size_of::<PlaybackStatus>(),
+        }
+    }
+}
+
+/// A message travelling over the shared bus
+#[derive(Debug, Clone)]
+pub struct BusMessage{
+    /// Publishing client, `None` for messages produced by the bridge itself
+    pub source: Option<ClientId>,
+    pub topic: String,
+    pub payload: Payload,
+}
+
+#[derive(Debug, Clone)]
 pub struct WSBridgeState{
     pub t_ms: u64,
     pub last_t_ms: Option<u64>,
     pub send_rate_hz: f64,
     pub use_arrow_ipc: bool,
+    pub flush_policy: FlushPolicy,
+    pub history: History,
+    pub bus: broadcast::Sender<Arc<BusMessage>>,
+    /// Commands for the running replay, `None` when nothing is replayed
+    pub playback: Option<mpsc::Sender<PlaybackCommand>>,
+    /// Last status broadcast, sent to clients as they connect
+    pub playback_status: Option<PlaybackStatus>,
+    /// Unbounded feed of the recorder, which unlike a bus receiver must not
+    /// skip messages when it falls behind
+    pub recorder: Option<std::sync::mpsc::Sender<Arc<BusMessage>>>,
+    next_client_id: ClientId,
 }
 
 pub type StateHandle = std::sync::Arc<std::sync::Mutex<WSBridgeState>>;
 
 impl Default for WSBridgeState{
     fn default() -> Self{
+        let (bus, _) = broadcast::channel(BUS_CAPACITY);
         Self{
             t_ms: 0,
             last_t_ms: None,
             send_rate_hz: 1000.0,
             use_arrow_ipc: true,
+            flush_policy: FlushPolicy::default(),
+            history: History::default(),
+            bus,
+            playback: None,
+            playback_status: None,
+            recorder: None,
+            next_client_id: 0,
         }
     }
 }
@@ -26,7 +92,7 @@ impl WSBridgeState{
         Default::default()
     }
 
-    pub fn as_handle(self) -> StateHandle{
+    pub fn into_handle(self) -> StateHandle{
         std::sync::Arc::new(std::sync::Mutex::new(self))
     }
 
@@ -37,4 +103,42 @@ impl WSBridgeState{
     pub fn update_last_t(&mut self){
         self.last_t_ms = Some(self.t_ms);
     }
-}
\ No newline at end of file
+
+    pub fn next_client_id(&mut self) -> ClientId{
+        let id = self.next_client_id;
+        self.next_client_id += 1;
+        id
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<BusMessage>>{
+        self.bus.subscribe()
+    }
+
+    /// Record a message and put it on the bus for every connection to filter.
+    pub fn publish(&mut self, source: Option<ClientId>, topic: &str, time: u64, payload: Payload){
+        self.history.record(topic, time, payload.clone());
+
+        let message = Arc::new(BusMessage{
+            source,
+            topic: topic.to_string(),
+            payload,
+        });
+        if let Some(recorder) = &self.recorder
+            && recorder.send(Arc::clone(&message)).is_err()
+        {
+            self.recorder = None;
+        }
+        // Sending only fails when nobody is connected, which is fine
+        let _ = self.bus.send(message);
+    }
+
+    /// Send the playback status to every connection without recording it
+    pub fn broadcast_status(&mut self, status: PlaybackStatus){
+        self.playback_status = Some(status.clone());
+        let _ = self.bus.send(Arc::new(BusMessage{
+            source: None,
+            topic: String::new(),
+            payload: Payload::Status(status),
+        }));
+    }
+}
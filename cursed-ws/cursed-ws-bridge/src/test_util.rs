@@ -0,0 +1,26 @@
+//! Helpers shared by the unit tests
+
+use std::path::{Path, PathBuf};
+
+/// A fresh directory under the system temp dir, removed again when dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// `name` keeps tests running at the same time apart
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("cursed-ws-bridge-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
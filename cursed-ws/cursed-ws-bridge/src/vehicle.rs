@@ -0,0 +1,437 @@
+//! Ground vehicle simulator used as a known ground truth.
+//!
+//! A kinematic bicycle model drives on flat ground. Commanded acceleration
+//! and steering are smooth periodic functions of time, so the vehicle drives
+//! overlapping loops that stay within a few tens of metres of the start.
+//! Pose, twist and IMU readings all come from the same integrated state and
+//! agree with each other:
+//!
+//! - `velocity` is the derivative of `position`
+//! - `acceleration` is the derivative of `velocity`
+//! - `angular_velocity.z` is the derivative of `yaw`
+//!
+//! Frames follow ROS conventions: world is x forward, y left, z up and the
+//! body frame is x forward along the vehicle. Angles are in radians.
+
+use crate::test_data::{TestData, TestPose, TestVec3};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::f64::consts::TAU;
+
+/// Standard gravity in m/s², felt by the IMU as an upward specific force
+pub const GRAVITY: f64 = 9.80665;
+
+/// Vehicle geometry and the shape of its driving pattern
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct VehicleParams {
+    /// Distance between the axles in m
+    pub wheelbase: f64,
+    /// Mean forward speed in m/s
+    pub cruise_speed: f64,
+    /// Fraction of `cruise_speed` the target speed varies by
+    pub speed_variation: f64,
+    /// Period of the speed variation in s
+    pub speed_period_s: f64,
+    /// Acceleration limit in m/s²
+    pub max_accel: f64,
+    /// Steering angle held on average in rad, non-zero keeps the vehicle looping
+    pub base_steer: f64,
+    /// Amplitude of the steering oscillation in rad
+    pub steer_amplitude: f64,
+    /// Period of the steering oscillation in s
+    pub steer_period_s: f64,
+    /// Gain from speed error to commanded acceleration in 1/s
+    pub speed_gain: f64,
+    /// Start position in the world frame in m
+    pub start: TestVec3,
+    /// Start heading in rad
+    pub start_yaw: f64,
+    pub battery: BatteryParams,
+}
+
+impl Default for VehicleParams {
+    fn default() -> Self {
+        Self {
+            wheelbase: 2.5,
+            cruise_speed: 3.0,
+            speed_variation: 0.5,
+            speed_period_s: 22.0,
+            max_accel: 2.0,
+            base_steer: 0.4,
+            steer_amplitude: 0.2,
+            steer_period_s: 11.0,
+            speed_gain: 1.0,
+            start: TestVec3::new(0.0, 0.0, 0.0),
+            start_yaw: 0.0,
+            battery: BatteryParams::default(),
+        }
+    }
+}
+
+impl VehicleParams {
+    /// A variation of these parameters for one agent of a fleet.
+    ///
+    /// The start is moved within a `spread_m` square around `start` and given
+    /// a random heading. Speeds, steering and periods are scaled by up to
+    /// `± variation`, and half of the agents loop the other way.
+    pub fn randomized(&self, spread_m: f64, variation: f64, rng: &mut StdRng) -> Self {
+        let mut scale = || 1.0 + variation * rng.gen_range(-1.0..=1.0);
+        let cruise_speed = self.cruise_speed * scale();
+        let base_steer = self.base_steer * scale();
+        let steer_amplitude = self.steer_amplitude * scale();
+        let speed_period_s = self.speed_period_s * scale();
+        let steer_period_s = self.steer_period_s * scale();
+
+        let half_spread = spread_m / 2.0;
+        let mut offset = || if half_spread > 0.0 { rng.gen_range(-half_spread..=half_spread) } else { 0.0 };
+        let start = TestVec3::new(self.start.x + offset(), self.start.y + offset(), self.start.z);
+        let direction = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+
+        Self {
+            cruise_speed,
+            base_steer: base_steer * direction,
+            steer_amplitude,
+            speed_period_s,
+            steer_period_s,
+            start,
+            start_yaw: rng.gen_range(0.0..TAU),
+            ..self.clone()
+        }
+    }
+}
+
+/// Unit quaternion, `w` the scalar part
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    /// Rotation from intrinsic Z-Y-X (yaw, pitch, roll) Euler angles
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Self {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+        Self {
+            w: cr * cp * cy + sr * sp * sy,
+            x: sr * cp * cy - cr * sp * sy,
+            y: cr * sp * cy + sr * cp * sy,
+            z: cr * cp * sy - sr * sp * cy,
+        }
+    }
+}
+
+/// Published on `<namespace>/pose`
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Pose {
+    pub position: TestVec3,
+    pub orientation: Quaternion,
+    /// The same orientation as roll (`x`), pitch (`y`) and yaw (`z`)
+    pub euler: TestVec3,
+}
+
+/// Published on `<namespace>/twist`, both vectors in the body frame
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Twist {
+    pub linear: TestVec3,
+    pub angular: TestVec3,
+}
+
+/// Published on `<namespace>/imu`, an ideal IMU at the rear axle
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Imu {
+    pub orientation: Quaternion,
+    /// Body-frame rates in rad/s
+    pub angular_velocity: TestVec3,
+    /// Body-frame specific force in m/s², including gravity
+    pub linear_acceleration: TestVec3,
+}
+
+/// Published on `<namespace>/battery`
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Battery {
+    /// Terminal voltage in V, sagging under load
+    pub voltage: f64,
+    /// Current drawn in A
+    pub current: f64,
+    /// Remaining charge from `0` to `1`
+    pub state_of_charge: f64,
+}
+
+/// Battery pack and the load the drivetrain puts on it
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct BatteryParams {
+    pub capacity_ah: f64,
+    /// Open-circuit voltage when full, it drops by 20% when empty
+    pub full_voltage: f64,
+    pub internal_resistance_ohm: f64,
+    /// Current drawn while standing still in A
+    pub idle_current: f64,
+    /// Extra current per m/s of speed in A
+    pub current_per_speed: f64,
+    /// Extra current per m/s² of forward acceleration in A
+    pub current_per_accel: f64,
+    /// Charge at start, from `0` to `1`
+    pub start_charge: f64,
+}
+
+impl Default for BatteryParams {
+    fn default() -> Self {
+        Self {
+            capacity_ah: 10.0,
+            full_voltage: 25.2,
+            internal_resistance_ohm: 0.05,
+            idle_current: 1.5,
+            current_per_speed: 2.0,
+            current_per_accel: 4.0,
+            start_charge: 1.0,
+        }
+    }
+}
+
+/// Integrated vehicle state, advanced one sample at a time
+#[derive(Debug, Clone)]
+pub struct VehicleSim {
+    params: VehicleParams,
+    position: TestVec3,
+    yaw: f64,
+    speed: f64,
+    yaw_rate: f64,
+    accel: f64,
+    charge: f64,
+    current: f64,
+}
+
+impl VehicleSim {
+    pub fn new(params: VehicleParams) -> Self {
+        Self {
+            position: params.start.clone(),
+            yaw: params.start_yaw,
+            speed: 0.0,
+            yaw_rate: 0.0,
+            accel: 0.0,
+            charge: params.battery.start_charge.clamp(0.0, 1.0),
+            current: params.battery.idle_current,
+            params,
+        }
+    }
+
+    fn steering(&self, t: f64) -> f64 {
+        self.params.base_steer + self.params.steer_amplitude * (TAU * t / self.params.steer_period_s).sin()
+    }
+
+    fn target_speed(&self, t: f64) -> f64 {
+        let variation = self.params.speed_variation * (TAU * t / self.params.speed_period_s).sin();
+        self.params.cruise_speed * (1.0 + variation)
+    }
+
+    /// Advance the model by `dt` seconds, ending at time `t`.
+    ///
+    /// Controls are held over the step and the pose is integrated at the
+    /// midpoint, which keeps the trajectory consistent with the reported
+    /// velocities at any sample rate. Nothing happens before `t = 0`, so the
+    /// sample at `t = 0` is the start state.
+    pub fn step(&mut self, t: f64, dt: f64) {
+        let dt = dt.min(t.max(0.0));
+        let speed_error = self.target_speed(t) - self.speed;
+        self.accel = (self.params.speed_gain * speed_error)
+            .clamp(-self.params.max_accel, self.params.max_accel);
+
+        let start_speed = self.speed;
+        self.speed = (self.speed + self.accel * dt).max(0.0);
+        // Stopping mid-step means the acceleration wasn't applied for all of it
+        if dt > 0.0 {
+            self.accel = (self.speed - start_speed) / dt;
+        }
+        let mid_speed = (start_speed + self.speed) / 2.0;
+
+        let steering = self.steering(t);
+        let start_yaw = self.yaw;
+        self.yaw_rate = self.speed * steering.tan() / self.params.wheelbase;
+        let mid_yaw_rate = mid_speed * steering.tan() / self.params.wheelbase;
+        self.yaw += mid_yaw_rate * dt;
+        let mid_yaw = (start_yaw + self.yaw) / 2.0;
+
+        self.position.x += mid_speed * mid_yaw.cos() * dt;
+        self.position.y += mid_speed * mid_yaw.sin() * dt;
+
+        // Braking doesn't recharge, the battery only ever drains
+        let battery = &self.params.battery;
+        self.current = battery.idle_current
+            + battery.current_per_speed * self.speed
+            + battery.current_per_accel * self.accel.max(0.0);
+        self.charge = (self.charge - self.current * dt / 3600.0 / battery.capacity_ah).max(0.0);
+    }
+
+    /// Heading wrapped to `(-π, π]`
+    pub fn yaw(&self) -> f64 {
+        let yaw = self.yaw.rem_euclid(TAU);
+        if yaw > TAU / 2.0 { yaw - TAU } else { yaw }
+    }
+
+    fn orientation(&self) -> Quaternion {
+        Quaternion::from_euler(0.0, 0.0, self.yaw())
+    }
+
+    /// Velocity in the world frame
+    pub fn velocity(&self) -> TestVec3 {
+        TestVec3::new(self.speed * self.yaw.cos(), self.speed * self.yaw.sin(), 0.0)
+    }
+
+    /// Acceleration in the world frame, tangential plus centripetal
+    pub fn acceleration(&self) -> TestVec3 {
+        let (sin, cos) = self.yaw.sin_cos();
+        let lateral = self.speed * self.yaw_rate;
+        TestVec3::new(self.accel * cos - lateral * sin, self.accel * sin + lateral * cos, 0.0)
+    }
+
+    pub fn pose(&self) -> Pose {
+        Pose {
+            position: self.position.clone(),
+            orientation: self.orientation(),
+            euler: TestVec3::new(0.0, 0.0, self.yaw()),
+        }
+    }
+
+    pub fn twist(&self) -> Twist {
+        Twist {
+            linear: TestVec3::new(self.speed, 0.0, 0.0),
+            angular: TestVec3::new(0.0, 0.0, self.yaw_rate),
+        }
+    }
+
+    pub fn imu(&self) -> Imu {
+        Imu {
+            orientation: self.orientation(),
+            angular_velocity: TestVec3::new(0.0, 0.0, self.yaw_rate),
+            linear_acceleration: TestVec3::new(self.accel, self.speed * self.yaw_rate, GRAVITY),
+        }
+    }
+
+    pub fn battery(&self) -> Battery {
+        let battery = &self.params.battery;
+        let open_circuit = battery.full_voltage * (0.8 + 0.2 * self.charge);
+        Battery {
+            voltage: open_circuit - self.current * battery.internal_resistance_ohm,
+            current: self.current,
+            state_of_charge: self.charge,
+        }
+    }
+
+    /// The state in the layout the web views expect, rotation as roll, pitch, yaw
+    pub fn test_data(&self) -> TestData {
+        TestData::new(
+            TestPose::new(self.position.clone(), TestVec3::new(0.0, 0.0, self.yaw())),
+            self.velocity(),
+            self.acceleration(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    const DT: f64 = 0.01;
+
+    fn wrap(angle: f64) -> f64 {
+        (angle + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64, what: &str) {
+        assert!((actual - expected).abs() <= tolerance, "{}: {} != {}", what, actual, expected);
+    }
+
+    /// Samples of a vehicle stepped at `DT` from `t = 0`, as the generator does
+    fn drive(params: VehicleParams, steps: usize) -> Vec<VehicleSim> {
+        let mut vehicle = VehicleSim::new(params);
+        (0..steps)
+            .map(|index| {
+                vehicle.step(index as f64 * DT, DT);
+                vehicle.clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_sample_is_the_start_state() {
+        let params = VehicleParams { start: TestVec3::new(1.0, 2.0, 0.5), start_yaw: 1.0, ..Default::default() };
+        let first = &drive(params.clone(), 1)[0];
+        assert_eq!(first.pose().position, params.start);
+        assert_eq!(first.yaw(), 1.0);
+        assert_eq!(first.twist().linear.x, 0.0);
+        assert_eq!(first.battery().state_of_charge, 1.0);
+    }
+
+    #[test]
+    fn reported_rates_are_derivatives_of_the_state() {
+        // Long enough to speed up, turn through several loops and slow down
+        let samples = drive(VehicleParams::default(), 3000);
+        for pair in samples.windows(2) {
+            let (before, after) = (pair[0].test_data(), pair[1].test_data());
+
+            let velocity = [
+                (after.pose.position.x - before.pose.position.x) / DT,
+                (after.pose.position.y - before.pose.position.y) / DT,
+            ];
+            assert_close(velocity[0], (before.velocity.x + after.velocity.x) / 2.0, 1e-3, "velocity x");
+            assert_close(velocity[1], (before.velocity.y + after.velocity.y) / 2.0, 1e-3, "velocity y");
+
+            let yaw_rate = wrap(after.pose.rotation.z - before.pose.rotation.z) / DT;
+            let reported = (pair[0].twist().angular.z + pair[1].twist().angular.z) / 2.0;
+            assert_close(yaw_rate, reported, 5e-3, "yaw rate");
+
+            let speed = (pair[1].twist().linear.x - pair[0].twist().linear.x) / DT;
+            assert_close(speed, pair[1].imu().linear_acceleration.x, 1e-9, "forward acceleration");
+
+            let acceleration = [
+                (after.velocity.x - before.velocity.x) / DT,
+                (after.velocity.y - before.velocity.y) / DT,
+            ];
+            assert_close(acceleration[0], (before.acceleration.x + after.acceleration.x) / 2.0, 2e-2, "acceleration x");
+            assert_close(acceleration[1], (before.acceleration.y + after.acceleration.y) / 2.0, 2e-2, "acceleration y");
+        }
+
+        let last = samples.last().unwrap();
+        assert!(last.twist().linear.x > 1.0, "the vehicle should be moving");
+        assert!(last.battery().state_of_charge < 1.0);
+    }
+
+    #[test]
+    fn randomized_agents_stay_within_spread_and_variation() {
+        let params = VehicleParams { start: TestVec3::new(10.0, 20.0, 0.0), ..Default::default() };
+        let mut rng = StdRng::seed_from_u64(5);
+        let agents: Vec<VehicleParams> = (0..200).map(|_| params.randomized(8.0, 0.3, &mut rng)).collect();
+        let within = |value: f64, base: f64| (value / base - 1.0).abs() <= 0.3 + 1e-12;
+        for agent in &agents {
+            assert!((agent.start.x - 10.0).abs() <= 4.0 && (agent.start.y - 20.0).abs() <= 4.0);
+            assert!((0.0..TAU).contains(&agent.start_yaw));
+            assert!(within(agent.cruise_speed, params.cruise_speed));
+            assert!(within(agent.base_steer.abs(), params.base_steer));
+            assert!(within(agent.steer_amplitude, params.steer_amplitude));
+            assert!(within(agent.speed_period_s, params.speed_period_s));
+            assert!(within(agent.steer_period_s, params.steer_period_s));
+            assert_eq!((agent.wheelbase, &agent.battery), (params.wheelbase, &params.battery));
+        }
+        // Both loop directions
+        assert!(agents.iter().any(|agent| agent.base_steer < 0.0) && agents.iter().any(|agent| agent.base_steer > 0.0));
+
+        let unspread = params.randomized(0.0, 0.0, &mut rng);
+        assert_eq!((unspread.start, unspread.cruise_speed), (params.start.clone(), params.cruise_speed));
+    }
+
+    #[test]
+    fn quaternions_match_the_euler_angles() {
+        let quaternion = Quaternion::from_euler(0.0, 0.0, TAU / 4.0);
+        let half = (TAU / 8.0).cos();
+        assert_close(quaternion.w, half, 1e-12, "w");
+        assert_close(quaternion.z, half, 1e-12, "z");
+        assert_eq!((quaternion.x, quaternion.y), (0.0, 0.0));
+    }
+}
@@ -1,18 +1,30 @@
-use crate::state::StateHandle;
-use log::{debug, error, info};
-use std::collections::BTreeMap;
+use crate::state::{BusMessage, ClientId, Payload, StateHandle};
+use log::{debug, error, info, warn};
 use warp::ws::{Message, WebSocket};
 use warp::{Rejection, Reply};
 type Result<T> = std::result::Result<T, Rejection>;
-use crate::test_data::TestData;
-use crate::datapoint::DataPoint;
-use futures::{FutureExt, SinkExt, StreamExt};
+use crate::arrow_serde::{shaped_arrow_struct, Shape};
+use crate::datapoint::{self, DataPoint};
+use crate::batcher::TopicBatcher;
+use crate::flatten::flatten_batch;
+use crate::ipc_stream::{StreamEncoder, StreamFrameDecoder};
+use crate::replay::{PlaybackCommand, PlaybackStatus};
+use futures::{SinkExt, StreamExt};
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::MissedTickBehavior;
 use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
 
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
 pub enum WSMessage {
     NewDatapoint(DataPoint),
     BinaryArrowData(Vec<u8>),
+    SyncRequest(SyncRequest),
+    /// Drives the bridge's replay, see `--replay`
+    PlaybackControl(PlaybackCommand),
+    /// Sent by the bridge to keep every client's playback clock in sync
+    PlaybackStatus(PlaybackStatus),
 }
 
 pub async fn ws_handler(ws: warp::ws::Ws, state: StateHandle) -> Result<impl Reply> {
@@ -21,12 +33,33 @@ pub async fn ws_handler(ws: warp::ws::Ws, state: StateHandle) -> Result<impl Rep
     }))
 }
 
+/// Sent by a client to choose which topics it receives.
+///
+/// An empty `topics` list subscribes to everything and a trailing `*` matches
+/// any topic with that prefix. When `range` is set, stored messages with
+/// `start <= time <= end` are sent back before live data resumes.
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
 pub struct SyncRequest {
     pub topics: Vec<String>,
     pub range: Option<(u64, u64)>,
 }
 
+impl SyncRequest {
+    pub fn all() -> Self {
+        Self { topics: Vec::new(), range: None }
+    }
+
+    pub fn matches(&self, topic: &str) -> bool {
+        if self.topics.is_empty() {
+            return true;
+        }
+        self.topics.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => topic.starts_with(prefix),
+            None => pattern == topic,
+        })
+    }
+}
+
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
 pub struct SyncUpdate {
     pub topic: String,
@@ -38,108 +71,250 @@ impl SyncUpdate {
         Self { topic, data }
     }
 
-    pub fn message(&self, time: u64) -> WSMessage {
-        WSMessage::NewDatapoint(DataPoint::with_json(
+    pub fn datapoint(&self, time: u64) -> DataPoint {
+        DataPoint::with_json(
             self.topic.clone(),
             time,
             serde_json::to_string(&self.data).unwrap(),
-        ))
-    }
-    
-    pub fn arrow_message(&self, time: u64, test_data: &TestData) -> std::result::Result<WSMessage, arrow::error::ArrowError> {
-        let datapoint = DataPoint::from_test_data(self.topic.clone(), time, test_data);
-        
-        let ipc_bytes = datapoint.to_ipc_bytes(test_data)?;
-        
-        Ok(WSMessage::BinaryArrowData(ipc_bytes))
+        )
     }
 }
 
-#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
-pub enum SyncMessage {
-    Update(SyncUpdate),
+/// One-row batch of `data` laid out as `shape` on `topic`, its fields
+/// flattened into dotted columns
+pub fn arrow_batch(topic: &str, time: u64, data: &serde_json::Value, shape: &Shape) -> std::result::Result<RecordBatch, arrow::error::ArrowError> {
+    let (_, array) = shaped_arrow_struct(data, shape, "data")?;
+    DataPoint::new(topic.to_string(), time, array).to_flattened_record_batch()
 }
 
 pub async fn ws_connect(ws: WebSocket, state: StateHandle) {
-    info!("New WebSocket connection");
+    let (mut client_ws_sender, mut client_ws_rcv) = ws.split();
 
-    let (mut client_ws_sender, _client_ws_rcv) = ws.split();
+    let (client_id, mut bus_rx, playback_status) = {
+        let mut state = state.lock().unwrap();
+        (state.next_client_id(), state.subscribe(), state.playback_status.clone())
+    };
+    info!("New WebSocket connection {}", client_id);
 
-    let use_arrow_ipc = state.lock().unwrap().use_arrow_ipc;
-    
-    let state_clone = state.clone();
-    let start_time = std::time::Instant::now();
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<Arc<BusMessage>>(512);
 
-    let (tx, mut rx) = tokio::sync::mpsc::channel::<WSMessage>(512);
+    // Late joiners start from the current playback clock
+    if let Some(status) = playback_status {
+        let _ = tx.try_send(Arc::new(BusMessage {
+            source: None,
+            topic: String::new(),
+            payload: Payload::Status(status),
+        }));
+    }
+    let (subscription_tx, subscription_rx) = tokio::sync::watch::channel(SyncRequest::all());
 
+    // Encode for this connection, keeping one Arrow stream per topic and
+    // coalescing rows that arrive within the flush window
+    let flush_policy = state.lock().unwrap().flush_policy;
     tokio::spawn(async move {
-        while let Some(message) = rx.recv().await {
-            let result = match &message {
-                WSMessage::NewDatapoint(_) => {
-                    let msg_json = serde_json::to_string(&message).unwrap();
-                    client_ws_sender.send(Message::text(msg_json)).await
+        let mut encoder = StreamEncoder::default();
+        let mut batcher = TopicBatcher::new(flush_policy);
+        // Immediate policies send on push and never need the timer
+        let mut flush_timer = (!flush_policy.is_immediate()).then(|| {
+            let mut timer = tokio::time::interval(std::time::Duration::from_millis(flush_policy.interval_ms));
+            timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            timer
+        });
+
+        'send: loop {
+            let frames = tokio::select! {
+                received = rx.recv() => {
+                    let Some(bus_message) = received else {
+                        break;
+                    };
+                    match &bus_message.payload {
+                        Payload::Datapoint(datapoint) => {
+                            let msg_json = serde_json::to_string(&datapoint.message()).unwrap();
+                            vec![Message::text(msg_json)]
+                        },
+                        Payload::Batch(batch) => {
+                            encode_batches(&mut encoder, batcher.push(&bus_message.topic, batch.clone()))
+                        }
+                        Payload::Status(status) => {
+                            let message = WSMessage::PlaybackStatus(status.clone());
+                            vec![Message::text(serde_json::to_string(&message).unwrap())]
+                        }
+                    }
                 },
-                WSMessage::BinaryArrowData(bytes) => {
-                    client_ws_sender.send(Message::binary(bytes.clone())).await
+                _ = async { flush_timer.as_mut().unwrap().tick().await }, if flush_timer.is_some() => {
+                    encode_batches(&mut encoder, batcher.flush())
                 }
             };
             
-            if let Err(e) = result {
-                error!("Error sending message: {}", e);
-                break;
+            for frame in frames {
+                if let Err(e) = client_ws_sender.send(frame).await {
+                    error!("Error sending message: {}", e);
+                    break 'send;
+                }
             }
         }
     });
 
-    tokio::spawn(async move {
-        let mut last_send_time = 0;
-        let send_interval = (1.0 / state_clone.lock().unwrap().send_rate_hz) * 1000.0;
+    // Filter the shared bus down to this client's subscription
+    let forward_tx = tx.clone();
+    let forwarder = tokio::spawn(async move {
         loop {
-            let loop_start = std::time::Instant::now();
-            let current_time = start_time.elapsed().as_millis() as u64;
-            if current_time - last_send_time >= send_interval as u64 {
-                last_send_time = current_time;
-                let state_clone = state.clone();
-                let t_sec = last_send_time as f64 / 1000.0;
-                let test_data = TestData::new_sin(t_sec);
-                
-                let sync_update = SyncUpdate::new(
-                    "test/topic".to_string(),
-                    vec![(
-                        last_send_time as u64,
-                        serde_json::to_string(&test_data).unwrap(),
-                    )],
-                );
-                
-                let message = if use_arrow_ipc {
-                    match sync_update.arrow_message(last_send_time as u64, &test_data) {
-                        Ok(msg) => msg,
-                        Err(e) => {
-                            error!("Error creating Arrow message: {}. Falling back to JSON", e);
-                            sync_update.message(last_send_time as u64)
-                        }
+            let bus_message = match bus_rx.recv().await {
+                Ok(bus_message) => bus_message,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Client {} lagged, skipped {} messages", client_id, skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            let is_status = matches!(bus_message.payload, Payload::Status(_));
+            if bus_message.source == Some(client_id)
+                || !(is_status || subscription_rx.borrow().matches(&bus_message.topic))
+            {
+                continue;
+            }
+
+            if let Err(e) = forward_tx.send(bus_message).await {
+                error!("Error sending to channel: {}", e);
+                break;
+            }
+        }
+    });
+
+    // Read inbound frames until the client disconnects
+    let mut decoder = StreamFrameDecoder::default();
+    while let Some(result) = client_ws_rcv.next().await {
+        let msg = match result {
+            Ok(msg) => msg,
+            Err(e) => {
+                error!("WebSocket error on client {}: {}", client_id, e);
+                break;
+            }
+        };
+        handle_client_message(client_id, msg, &state, &tx, &subscription_tx, &mut decoder).await;
+    }
+
+    forwarder.abort();
+    info!("WebSocket connection {} closed", client_id);
+}
+
+fn encode_batches(
+    encoder: &mut StreamEncoder,
+    batches: std::result::Result<Vec<(String, RecordBatch)>, ArrowError>,
+) -> Vec<Message> {
+    let batches = match batches {
+        Ok(batches) => batches,
+        Err(e) => {
+            error!("Error combining Arrow batches: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut frames = Vec::new();
+    for (topic, batch) in batches {
+        match encoder.encode(&topic, &batch) {
+            Ok(encoded) => frames.extend(encoded.into_iter().map(Message::binary)),
+            Err(e) => error!("Error encoding Arrow batch for {}: {}", topic, e),
+        }
+    }
+    frames
+}
+
+async fn handle_client_message(
+    client_id: ClientId,
+    msg: Message,
+    state: &StateHandle,
+    client_tx: &tokio::sync::mpsc::Sender<Arc<BusMessage>>,
+    subscription_tx: &tokio::sync::watch::Sender<SyncRequest>,
+    decoder: &mut StreamFrameDecoder,
+) {
+    let message = if msg.is_binary() {
+        WSMessage::BinaryArrowData(msg.into_bytes())
+    } else if let Ok(text) = msg.to_str() {
+        match serde_json::from_str::<WSMessage>(text) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!("Ignoring malformed message from client {}: {}", client_id, e);
+                return;
+            }
+        }
+    } else {
+        // Ping/pong/close frames are handled by warp
+        return;
+    };
+
+    match message {
+        WSMessage::NewDatapoint(datapoint) => {
+            debug!("Client {} published on {}", client_id, datapoint.topic);
+            let (topic, time) = (datapoint.topic.clone(), datapoint.time);
+            state.lock().unwrap().publish(Some(client_id), &topic, time, Payload::Datapoint(datapoint));
+        }
+        WSMessage::BinaryArrowData(bytes) => {
+            let (topic, batches) = match decoder.decode(&bytes) {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    warn!("Ignoring undecodable frame from client {}: {}", client_id, e);
+                    return;
+                }
+            };
+
+            let mut state = state.lock().unwrap();
+            for batch in batches {
+                // Clients may publish nested structs, subscribers get dotted columns
+                let batch = match flatten_batch(&batch) {
+                    Ok(batch) => batch,
+                    Err(e) => {
+                        warn!("Ignoring batch from client {}: {}", client_id, e);
+                        continue;
                     }
-                } else {
-                    sync_update.message(last_send_time as u64)
                 };
-                
-                state_clone.lock().unwrap().tick(send_interval as u64);
-                state_clone.lock().unwrap().update_last_t();
-                
-                if let Err(e) = tx.send(message).await {
-                    error!("Error sending to channel: {}", e);
-                    break;
+                match datapoint::batch_time(&batch) {
+                    Ok(time) => {
+                        debug!("Client {} published on {}", client_id, topic);
+                        state.publish(Some(client_id), &topic, time, Payload::Batch(batch));
+                    }
+                    Err(e) => warn!("Ignoring batch from client {}: {}", client_id, e),
                 }
             }
-            
-            let elapsed = loop_start.elapsed().as_millis() as u64;
-            let sleep_time = if elapsed < send_interval as u64 {
-                send_interval as u64 - elapsed
-            } else {
-                0
+        }
+        WSMessage::SyncRequest(request) => {
+            handle_sync_request(client_id, request, state, client_tx, subscription_tx).await;
+        }
+        WSMessage::PlaybackControl(command) => {
+            let playback = state.lock().unwrap().playback.clone();
+            let Some(playback) = playback else {
+                warn!("Ignoring playback command from client {}, nothing is being replayed", client_id);
+                return;
             };
-            
-            tokio::time::sleep(std::time::Duration::from_millis(sleep_time)).await;
+            info!("Client {} sent playback command {:?}", client_id, command);
+            if let Err(e) = playback.send(command).await {
+                error!("Error sending playback command: {}", e);
+            }
         }
-    });
+        WSMessage::PlaybackStatus(_) => {
+            warn!("Ignoring playback status from client {}", client_id);
+        }
+    }
+}
+
+async fn handle_sync_request(
+    client_id: ClientId,
+    request: SyncRequest,
+    state: &StateHandle,
+    client_tx: &tokio::sync::mpsc::Sender<Arc<BusMessage>>,
+    subscription_tx: &tokio::sync::watch::Sender<SyncRequest>,
+) {
+    info!("Client {} subscribed to {:?} (range {:?})", client_id, request.topics, request.range);
+
+    subscription_tx.send_replace(request.clone());
+    let backfill = state.lock().unwrap().history.query(&request);
+
+    debug!("Sending {} stored samples to client {}", backfill.len(), client_id);
+    for bus_message in backfill {
+        if let Err(e) = client_tx.send(Arc::new(bus_message)).await {
+            error!("Error sending history to client {}: {}", client_id, e);
+            break;
+        }
+    }
 }
@@ -0,0 +1,344 @@
+"""Writes spec.mcap, an MCAP file assembled by hand from https://mcap.dev/spec
+so the reader is tested against bytes our writer did not produce.
+
+Every record type has a builder below taking the fields in the order the spec
+lists them. Chunks are compressed with the zstd and lz4 command line tools.
+Run from anywhere:
+
+    python3 testdata/make_spec_mcap.py
+"""
+
+import json
+import os
+import struct
+import subprocess
+import zlib
+
+MAGIC = b"\x89MCAP0\r\n"
+
+OP_HEADER = 0x01
+OP_FOOTER = 0x02
+OP_SCHEMA = 0x03
+OP_CHANNEL = 0x04
+OP_MESSAGE = 0x05
+OP_CHUNK = 0x06
+OP_MESSAGE_INDEX = 0x07
+OP_CHUNK_INDEX = 0x08
+OP_ATTACHMENT = 0x09
+OP_STATISTICS = 0x0B
+OP_METADATA = 0x0C
+OP_SUMMARY_OFFSET = 0x0E
+OP_DATA_END = 0x0F
+
+
+# Field encodings, all integers are little-endian
+
+
+def uint8(value):
+    return struct.pack("<B", value)
+
+
+def uint16(value):
+    return struct.pack("<H", value)
+
+
+def uint32(value):
+    return struct.pack("<I", value)
+
+
+def uint64(value):
+    return struct.pack("<Q", value)
+
+
+def string(value):
+    """uint32 byte length, then UTF-8"""
+    encoded = value.encode()
+    return uint32(len(encoded)) + encoded
+
+
+def bytes32(value):
+    """uint32 byte length, then the bytes"""
+    return uint32(len(value)) + value
+
+
+def length_prefixed(entries):
+    """A map or array: uint32 byte length of the entries, then the entries"""
+    body = b"".join(entries)
+    return uint32(len(body)) + body
+
+
+def string_map(entries):
+    """Map<string, string>"""
+    return length_prefixed(string(key) + string(value) for key, value in entries.items())
+
+
+def record(opcode, *fields):
+    """Opcode, uint64 content length and the content"""
+    content = b"".join(fields)
+    return uint8(opcode) + uint64(len(content)) + content
+
+
+def ms(milliseconds):
+    """Timestamps are in ns"""
+    return milliseconds * 1_000_000
+
+
+# Records, one builder per record type
+
+
+def header(profile, library):
+    return record(OP_HEADER, string(profile), string(library))
+
+
+def schema(schema_id, name, encoding, data):
+    return record(OP_SCHEMA, uint16(schema_id), string(name), string(encoding), bytes32(data))
+
+
+def channel(channel_id, schema_id, topic, message_encoding, metadata):
+    return record(
+        OP_CHANNEL,
+        uint16(channel_id),
+        uint16(schema_id),
+        string(topic),
+        string(message_encoding),
+        string_map(metadata),
+    )
+
+
+def message(channel_id, sequence, log_time, publish_time, data):
+    return record(OP_MESSAGE, uint16(channel_id), uint32(sequence), uint64(log_time), uint64(publish_time), data)
+
+
+def chunk(message_start_time, message_end_time, records, compression, compressed):
+    """`records` uncompressed, `compressed` the same records compressed"""
+    return record(
+        OP_CHUNK,
+        uint64(message_start_time),
+        uint64(message_end_time),
+        uint64(len(records)),
+        uint32(zlib.crc32(records)),
+        string(compression),
+        uint64(len(compressed)),
+        compressed,
+    )
+
+
+def message_index(channel_id, entries):
+    """`entries` are (log time, offset into the uncompressed chunk records)"""
+    return record(
+        OP_MESSAGE_INDEX,
+        uint16(channel_id),
+        length_prefixed(uint64(log_time) + uint64(offset) for log_time, offset in entries),
+    )
+
+
+def chunk_index(
+    message_start_time,
+    message_end_time,
+    chunk_start_offset,
+    chunk_length,
+    message_index_offsets,
+    message_index_length,
+    compression,
+    compressed_size,
+    uncompressed_size,
+):
+    """`message_index_offsets` maps channel ids to file offsets"""
+    return record(
+        OP_CHUNK_INDEX,
+        uint64(message_start_time),
+        uint64(message_end_time),
+        uint64(chunk_start_offset),
+        uint64(chunk_length),
+        length_prefixed(uint16(channel_id) + uint64(offset) for channel_id, offset in message_index_offsets.items()),
+        uint64(message_index_length),
+        string(compression),
+        uint64(compressed_size),
+        uint64(uncompressed_size),
+    )
+
+
+def attachment(log_time, create_time, name, media_type, data):
+    return record(
+        OP_ATTACHMENT,
+        uint64(log_time),
+        uint64(create_time),
+        string(name),
+        string(media_type),
+        uint64(len(data)),
+        data,
+        uint32(zlib.crc32(data)),
+    )
+
+
+def metadata(name, entries):
+    return record(OP_METADATA, string(name), string_map(entries))
+
+
+def statistics(
+    message_count,
+    schema_count,
+    channel_count,
+    attachment_count,
+    metadata_count,
+    chunk_count,
+    message_start_time,
+    message_end_time,
+    channel_message_counts,
+):
+    """`channel_message_counts` maps channel ids to message counts"""
+    return record(
+        OP_STATISTICS,
+        uint64(message_count),
+        uint16(schema_count),
+        uint32(channel_count),
+        uint32(attachment_count),
+        uint32(metadata_count),
+        uint32(chunk_count),
+        uint64(message_start_time),
+        uint64(message_end_time),
+        length_prefixed(uint16(channel_id) + uint64(count) for channel_id, count in channel_message_counts.items()),
+    )
+
+
+def data_end(data_section):
+    """The CRC covers everything before it, starting at the leading magic"""
+    head = uint8(OP_DATA_END) + uint64(4)
+    return head + uint32(zlib.crc32(bytes(data_section) + head))
+
+
+def summary_offset(group_opcode, group_start, group_length):
+    return record(OP_SUMMARY_OFFSET, uint8(group_opcode), uint64(group_start), uint64(group_length))
+
+
+def footer(summary_start, summary_offset_start, summary_section):
+    """The CRC covers the summary section and the footer up to the CRC"""
+    head = uint8(OP_FOOTER) + uint64(20) + uint64(summary_start) + uint64(summary_offset_start)
+    return head + uint32(zlib.crc32(bytes(summary_section) + head))
+
+
+def compress(tool, data):
+    command = {"zstd": ["zstd", "-q", "-c"], "lz4": ["lz4", "-q", "-c"]}[tool]
+    return subprocess.run(command, input=data, capture_output=True, check=True).stdout
+
+
+def offset_of(records, target):
+    """Offset of `target` in the concatenated `records`"""
+    return len(b"".join(records[: records.index(target)]))
+
+
+# Schemas, channels and messages
+
+pose_json_schema = json.dumps(
+    {"type": "object", "properties": {"x": {"type": "number"}, "y": {"type": "number"}}}
+).encode()
+pose_schema = schema(1, "Pose", "jsonschema", pose_json_schema)
+image_schema = schema(2, "sensor_msgs/msg/Image", "ros2msg", b"uint32 height\nuint32 width\n")
+
+pose_channel = channel(1, 1, "/robot/pose", "json", {"frame_id": "map"})
+# Schema 0 means schemaless
+battery_channel = channel(2, 0, "/battery", "json", {})
+camera_channel = channel(3, 2, "/camera", "cdr", {})
+
+first_pose = message(1, 0, ms(1000), ms(1005), b'{"x":1.5,"y":-2.0}')
+second_pose = message(1, 1, ms(1100), ms(1105), b'{"x":1.75,"y":-1.5}')
+# CDR encapsulation header, then a 2 by 2 image
+image = message(3, 0, ms(1020), ms(1020), b"\x00\x01\x00\x00" + uint32(2) + uint32(2))
+battery = message(2, 0, ms(1050), ms(1050), b"12.5")
+
+# Data section: header, a zstd and an lz4 chunk each followed by their
+# message indexes, an attachment, metadata and the data end
+
+out = bytearray(MAGIC)
+out += header("", "hand-assembled fixture")
+
+zstd_records = [pose_schema, pose_channel, first_pose, second_pose]
+zstd_uncompressed = b"".join(zstd_records)
+zstd_compressed = compress("zstd", zstd_uncompressed)
+zstd_chunk_start = len(out)
+out += chunk(ms(1000), ms(1100), zstd_uncompressed, "zstd", zstd_compressed)
+zstd_chunk_length = len(out) - zstd_chunk_start
+
+zstd_indexes_start = len(out)
+pose_index_offset = len(out)
+out += message_index(
+    1,
+    [(ms(1000), offset_of(zstd_records, first_pose)), (ms(1100), offset_of(zstd_records, second_pose))],
+)
+zstd_indexes_length = len(out) - zstd_indexes_start
+
+lz4_records = [image_schema, battery_channel, camera_channel, image, battery]
+lz4_uncompressed = b"".join(lz4_records)
+lz4_compressed = compress("lz4", lz4_uncompressed)
+lz4_chunk_start = len(out)
+out += chunk(ms(1020), ms(1050), lz4_uncompressed, "lz4", lz4_compressed)
+lz4_chunk_length = len(out) - lz4_chunk_start
+
+# Message indexes go in channel id order
+lz4_indexes_start = len(out)
+battery_index_offset = len(out)
+out += message_index(2, [(ms(1050), offset_of(lz4_records, battery))])
+camera_index_offset = len(out)
+out += message_index(3, [(ms(1020), offset_of(lz4_records, image))])
+lz4_indexes_length = len(out) - lz4_indexes_start
+
+out += attachment(ms(1200), ms(1200), "notes.txt", "text/plain", b"hello")
+out += metadata("run", {"operator": "test"})
+out += data_end(out)
+
+# Summary section: schema, channel, statistics and chunk index groups, then an
+# offset to each group
+
+summary_start = len(out)
+
+schema_group_start = len(out)
+out += pose_schema
+out += image_schema
+schema_group_length = len(out) - schema_group_start
+
+channel_group_start = len(out)
+out += pose_channel
+out += battery_channel
+out += camera_channel
+channel_group_length = len(out) - channel_group_start
+
+statistics_group_start = len(out)
+out += statistics(4, 2, 3, 1, 1, 2, ms(1000), ms(1100), {1: 2, 2: 1, 3: 1})
+statistics_group_length = len(out) - statistics_group_start
+
+chunk_index_group_start = len(out)
+out += chunk_index(
+    ms(1000),
+    ms(1100),
+    zstd_chunk_start,
+    zstd_chunk_length,
+    {1: pose_index_offset},
+    zstd_indexes_length,
+    "zstd",
+    len(zstd_compressed),
+    len(zstd_uncompressed),
+)
+out += chunk_index(
+    ms(1020),
+    ms(1050),
+    lz4_chunk_start,
+    lz4_chunk_length,
+    {2: battery_index_offset, 3: camera_index_offset},
+    lz4_indexes_length,
+    "lz4",
+    len(lz4_compressed),
+    len(lz4_uncompressed),
+)
+chunk_index_group_length = len(out) - chunk_index_group_start
+
+summary_offset_start = len(out)
+out += summary_offset(OP_SCHEMA, schema_group_start, schema_group_length)
+out += summary_offset(OP_CHANNEL, channel_group_start, channel_group_length)
+out += summary_offset(OP_STATISTICS, statistics_group_start, statistics_group_length)
+out += summary_offset(OP_CHUNK_INDEX, chunk_index_group_start, chunk_index_group_length)
+
+out += footer(summary_start, summary_offset_start, out[summary_start:])
+out += MAGIC
+
+with open(os.path.join(os.path.dirname(os.path.abspath(__file__)), "spec.mcap"), "wb") as file:
+    file.write(out)
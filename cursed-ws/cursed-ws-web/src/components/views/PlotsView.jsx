@@ -37,6 +37,7 @@ export function PlotsView() {
   const { 
     latestMessage, 
     messagesByTopic, 
+    samplesByTopic,
     isConnected, 
     messageCount 
   } = useWebSocketContext();
@@ -49,6 +50,8 @@ export function PlotsView() {
   const [viewMode, setViewMode] = useState('chart');
   const [debugMode, setDebugMode] = useState(false);
   const [maxPoints, setMaxPoints] = useState(100);
+  // Arrow rows received before this are not plotted
+  const [clearedAt, setClearedAt] = useState(0);
   
   // Generate a consistent color for each field
   const getFieldColor = (field) => {
@@ -164,6 +167,27 @@ export function PlotsView() {
     }
   };
   
+  // Flatten an Arrow row into a data point, nested fields joined with dots
+  const flattenSample = (sample) => {
+    const result = {
+      timestamp: sample.timestamp,
+      serverTime: sample.time,
+      topic: sample.topic
+    };
+    const visit = (prefix, value) => {
+      if (typeof value === 'bigint') {
+        result[prefix] = Number(value);
+      } else if (value !== null && typeof value === 'object' && !Array.isArray(value)) {
+        const entries = typeof value.toJSON === 'function' ? value.toJSON() : value;
+        Object.entries(entries).forEach(([key, inner]) => visit(`${prefix}.${key}`, inner));
+      } else {
+        result[prefix] = value;
+      }
+    };
+    Object.entries(sample.data).forEach(([key, value]) => visit(key, value));
+    return result;
+  };
+  
   // Extract available fields from a data point
   const extractFields = (dataPoint) => {
     if (!dataPoint) return [];
@@ -190,6 +214,15 @@ export function PlotsView() {
   useEffect(() => {
     if (!latestMessage) return;
     
+    // Arrow rows are plotted from samplesByTopic
+    if (latestMessage.isArrowIPC) {
+      if (!selectedTopic) {
+        setSelectedTopic(latestMessage.topic);
+        addDebugLog('Auto-selected topic', latestMessage.topic);
+      }
+      return;
+    }
+    
     const parsed = parseDataPoint(latestMessage);
     if (!parsed) return;
     
@@ -280,9 +313,38 @@ export function PlotsView() {
     }
   }, [selectedTopic, messagesByTopic]);
   
+  // Plot every Arrow row received for the selected topic, not just the
+  // newest one of each batch
+  const samples = samplesByTopic[selectedTopic];
+  useEffect(() => {
+    if (!samples || samples.length === 0) return;
+    
+    const fields = extractFields(flattenSample(samples[samples.length - 1]));
+    setAvailableFields(fields);
+    if (selectedFields.length === 0 && fields.length > 0) {
+      setSelectedFields(fields.slice(0, 3).map(f => f.value));
+      return;
+    }
+    
+    setDataPoints(samples
+      .filter(sample => sample.timestamp > clearedAt)
+      .slice(-maxPoints)
+      .map(sample => {
+        const flat = flattenSample(sample);
+        const point = { timestamp: flat.timestamp };
+        selectedFields.forEach(field => {
+          if (flat[field] !== undefined) {
+            point[field] = flat[field];
+          }
+        });
+        return point;
+      }));
+  }, [samples, selectedFields, maxPoints, clearedAt]);
+  
   // Handle clearing the graph
   const handleClearData = () => {
     setDataPoints([]);
+    setClearedAt(Date.now());
     addDebugLog('Cleared data points');
   };
   
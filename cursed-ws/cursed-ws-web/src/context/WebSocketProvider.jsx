@@ -4,6 +4,25 @@ import { tableFromIPC } from 'apache-arrow';
 const DEFAULT_ADDRESS = '127.0.0.1';
 const DEFAULT_PORT = 3031;
 
+// Binary frame kinds sent by the bridge, see cursed-ws-bridge/src/ipc_stream.rs
+const FRAME_KIND_SCHEMA = 0;
+const FRAME_KIND_RECORD_BATCH = 1;
+// Arrow rows kept per topic for plotting
+const MAX_SAMPLES_PER_TOPIC = 10000;
+const textDecoder = new TextDecoder();
+
+/**
+ * Split a binary frame into its kind, topic and Arrow IPC stream bytes
+ */
+function decodeFrame(buffer) {
+  const view = new DataView(buffer);
+  const kind = view.getUint8(0);
+  const topicLength = view.getUint16(1, true);
+  const topic = textDecoder.decode(new Uint8Array(buffer, 3, topicLength));
+  const ipc = new Uint8Array(buffer, 3 + topicLength);
+  return { kind, topic, ipc };
+}
+
 // Create the context
 const WebSocketContext = createContext({});
 
@@ -29,46 +48,76 @@ export function WebSocketProvider({ children }) {
   const rateIntervalRef = useRef(null);
   const lastMessagesRef = useRef([]);
   const wsRef = useRef(null);
+  // Arrow schema message bytes per topic, sent once per connection
+  const schemasRef = useRef({});
   
   // Message storage
   const [latestMessage, setLatestMessage] = useState(null);
   const [messagesByTopic, setMessagesByTopic] = useState({});
+  // Every Arrow row received per topic, oldest first
+  const [samplesByTopic, setSamplesByTopic] = useState({});
   
-  // Parse Arrow IPC buffer
+  // Parse an Arrow IPC stream frame
   const parseArrowIPC = useCallback((buffer) => {
     try {
-      // Convert ArrayBuffer to Arrow Table
-      const table = tableFromIPC(buffer);
+      const frame = decodeFrame(buffer);
+
+      // Remember the schema, record batches for this topic are decoded against it
+      if (frame.kind === FRAME_KIND_SCHEMA) {
+        schemasRef.current[frame.topic] = frame.ipc;
+        return null;
+      }
+
+      const schema = schemasRef.current[frame.topic];
+      if (frame.kind !== FRAME_KIND_RECORD_BATCH || !schema) {
+        console.warn(`Dropping frame for ${frame.topic} without a schema`);
+        return null;
+      }
+
+      const stream = new Uint8Array(schema.byteLength + frame.ipc.byteLength);
+      stream.set(schema, 0);
+      stream.set(frame.ipc, schema.byteLength);
+
+      // Convert the stream bytes to an Arrow Table
+      const table = tableFromIPC(stream);
 
-      console.log('Parsed Arrow table:', table);
-      // Convert Arrow table to array of objects
-      const tableArray = table.toArray();
-      
       if (table.numRows === 0) {
         console.warn('Received empty Arrow table');
         return null;
       }
-      
-      // Since tableArray gives us an array of structs with all fields,
-      // we can directly use the first row
-      const row = tableArray[0];
-      
-      // Extract data from the table
+
+      // Batches carry every sample of the bridge's flush window. Their
+      // timestamps are spread back from now by their data time.
+      const receivedAt = Date.now();
+      const tableArray = table.toArray();
+      const newestTime = Number(tableArray[tableArray.length - 1].time);
+      const rows = tableArray.map((row) => {
+        const sample = {
+          topic: row.topic,
+          time: row.time,
+          timestamp: receivedAt - (newestTime - Number(row.time)),
+          data: {},
+        };
+        for (const key in row) {
+          if (key !== 'topic' && key !== 'time') {
+            sample.data[key] = row[key];
+          }
+        }
+        return sample;
+      });
+
+      // The newest row stands for the frame, `rows` has all of them
+      const newest = rows[rows.length - 1];
       const result = {
-        topic: row.topic,
-        time: row.time,
+        topic: newest.topic,
+        time: newest.time,
         isArrowIPC: true,
+        numRows: table.numRows,
         table,
-        data: {},
+        rows,
+        data: newest.data,
       };
       
-      // Extract all other fields as data
-      for (const key in row) {
-        if (key !== 'topic' && key !== 'time') {
-          result.data[key] = row[key];
-        }
-      }
-      
       return result;
     } catch (error) {
       console.error('Error parsing Arrow IPC data:', error);
@@ -96,6 +145,9 @@ export function WebSocketProvider({ children }) {
       
       wsRef.current = ws;
       
+      // Schemas are per connection
+      schemasRef.current = {};
+
       // Start timing for rate calculation
       startTimeRef.current = Date.now();
       setMessageCount(0);
@@ -157,6 +209,14 @@ export function WebSocketProvider({ children }) {
             
             if (arrowData) {
               setLatestMessage(arrowData);
+
+              // Appended with an updater so no row is lost when React
+              // coalesces several frames into one render
+              setSamplesByTopic(prev => ({
+                ...prev,
+                [arrowData.topic]: [...(prev[arrowData.topic] || []), ...arrowData.rows]
+                  .slice(-MAX_SAMPLES_PER_TOPIC)
+              }));
               
               // Store by topic
               setMessagesByTopic(prev => ({
@@ -205,6 +265,7 @@ export function WebSocketProvider({ children }) {
   const clearMessage = useCallback(() => {
     setLatestMessage(null);
     setMessagesByTopic({});
+    setSamplesByTopic({});
   }, []);
   
   // Clean up on unmount
@@ -237,6 +298,7 @@ export function WebSocketProvider({ children }) {
     // Messages
     latestMessage,
     messagesByTopic,
+    samplesByTopic,
     clearMessage,
     
     // Stats
@@ -82,6 +82,23 @@ pub fn cursed_load_csv(contents: &str) {
     core.from_csv(contents);
 }
 
+/// Add one live sample, such as a `DataPointMsg` from the `Subscribe` stream
+#[wasm_bindgen]
+pub fn cursed_add_number(key: &str, time: u64, value: f64) {
+    let core = CursedCore::global();
+    let mut core = core.lock().unwrap();
+    core.add_data(key.to_string(), time, CursedValue::Number(value));
+    core.current_time_ms = core.current_time_ms.max(time);
+}
+
+#[wasm_bindgen]
+pub fn cursed_add_string(key: &str, time: u64, value: &str) {
+    let core = CursedCore::global();
+    let mut core = core.lock().unwrap();
+    core.add_data(key.to_string(), time, CursedValue::String(value.to_string()));
+    core.current_time_ms = core.current_time_ms.max(time);
+}
+
 #[wasm_bindgen]
 pub fn cursed_random_data() {
     let core = CursedCore::global();
@@ -1,4 +1,10 @@
+use std::{env, path::PathBuf};
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::compile_protos("../cursed-proto/cursed.proto")?;
+    // Served by the reflection service
+    let descriptor_path = PathBuf::from(env::var("OUT_DIR")?).join("cursed_descriptor.bin");
+    tonic_build::configure()
+        .file_descriptor_set_path(descriptor_path)
+        .compile_protos(&["../cursed-proto/cursed.proto"], &["../cursed-proto"])?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,247 @@
+use std::{
+    ffi::OsStr,
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use tonic::Status;
+
+use crate::cursed::FileEntry;
+
+/// Directory the server reads and writes files in. Requests name files
+/// relative to it and can't reach anything outside, symlinks included.
+#[derive(Debug, Clone)]
+pub struct DataRoot {
+    root: PathBuf,
+}
+
+/// gRPC status for a failed file operation on `path`
+pub fn io_status(path: &str, e: io::Error) -> Status {
+    let message = format!("{}: {}", path, e);
+    match e.kind() {
+        io::ErrorKind::NotFound => Status::not_found(message),
+        io::ErrorKind::PermissionDenied => Status::permission_denied(message),
+        io::ErrorKind::InvalidData => Status::invalid_argument(message),
+        io::ErrorKind::AlreadyExists => Status::already_exists(message),
+        _ => Status::internal(message),
+    }
+}
+
+impl DataRoot {
+    pub fn new(root: impl AsRef<Path>) -> io::Result<Self> {
+        let root = root.as_ref().canonicalize()?;
+        if !root.is_dir() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} is not a directory", root.display())));
+        }
+        Ok(Self { root })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.root
+    }
+
+    /// Whether the root can still be listed, it may have been removed or
+    /// unmounted since the server started
+    pub fn check_readable(&self) -> io::Result<()> {
+        std::fs::read_dir(&self.root).map(|_| ())
+    }
+
+    /// Only plain names are allowed, no `..`, root or drive prefixes
+    fn check_relative(path: &str) -> Result<&Path, Status> {
+        let relative = Path::new(path);
+        if !relative.components().all(|component| matches!(component, Component::Normal(_) | Component::CurDir)) {
+            return Err(Status::invalid_argument(format!("{:?} must be a relative path inside the data root", path)));
+        }
+        Ok(relative)
+    }
+
+    /// Canonical path of an existing file or directory, `""` being the root
+    pub fn resolve(&self, path: &str) -> Result<PathBuf, Status> {
+        let relative = Self::check_relative(path)?;
+        let resolved = self.root.join(relative).canonicalize()
+            .map_err(|e| io_status(path, e))?;
+        self.check_inside(path, resolved)
+    }
+
+    /// Canonical path of an existing regular file
+    pub fn resolve_file(&self, path: &str) -> Result<PathBuf, Status> {
+        if path.is_empty() {
+            return Err(Status::invalid_argument("No path given"));
+        }
+        let resolved = self.resolve(path)?;
+        if !resolved.is_file() {
+            return Err(Status::invalid_argument(format!("{:?} is not a file", path)));
+        }
+        Ok(resolved)
+    }
+
+    /// Read a file as UTF-8 text
+    pub fn read_to_string(&self, path: &str) -> Result<String, Status> {
+        std::fs::read_to_string(self.resolve_file(path)?).map_err(|e| io_status(path, e))
+    }
+
+    /// Path for a file ending in `.{extension}` that may not exist yet, in an
+    /// existing directory. Only files with that extension can be written, so
+    /// the data files next to them can't be overwritten. An existing symlink
+    /// there is refused rather than written through.
+    pub fn resolve_new(&self, path: &str, extension: &str) -> Result<PathBuf, Status> {
+        let relative = Self::check_relative(path)?;
+        let name = relative.file_name()
+            .ok_or_else(|| Status::invalid_argument(format!("{:?} has no file name", path)))?;
+        if relative.extension() != Some(OsStr::new(extension)) {
+            return Err(Status::invalid_argument(format!("{:?} must end in .{}", path, extension)));
+        }
+        let parent = relative.parent().map_or(String::new(), |parent| parent.to_string_lossy().into_owned());
+        let resolved = self.resolve(&parent)?.join(name);
+        match std::fs::symlink_metadata(&resolved) {
+            Ok(metadata) if metadata.file_type().is_symlink() => {
+                Err(Status::permission_denied(format!("{:?} is a symlink", path)))
+            }
+            Ok(metadata) if metadata.is_dir() => Err(Status::invalid_argument(format!("{:?} is a directory", path))),
+            Ok(_) => Ok(resolved),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(resolved),
+            Err(e) => Err(io_status(path, e)),
+        }
+    }
+
+    fn check_inside(&self, path: &str, resolved: PathBuf) -> Result<PathBuf, Status> {
+        if !resolved.starts_with(&self.root) {
+            return Err(Status::permission_denied(format!("{:?} is outside the data root", path)));
+        }
+        Ok(resolved)
+    }
+
+    /// Entries of a directory, directories first, then by name
+    pub fn list(&self, path: &str) -> Result<Vec<FileEntry>, Status> {
+        let dir = self.resolve(path)?;
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(&dir).map_err(|e| io_status(path, e))? {
+            let entry = entry.map_err(|e| io_status(path, e))?;
+            // Symlinks are followed, and hidden when broken or leading out
+            let Ok(target) = entry.path().canonicalize() else {
+                continue;
+            };
+            if !target.starts_with(&self.root) {
+                continue;
+            }
+            let metadata = std::fs::metadata(&target).map_err(|e| io_status(path, e))?;
+            let relative = dir.join(entry.file_name());
+            let relative = relative.strip_prefix(&self.root).unwrap_or(&relative);
+            entries.push(FileEntry {
+                path: relative.components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+                is_dir: metadata.is_dir(),
+                size_bytes: if metadata.is_dir() { 0 } else { metadata.len() },
+            });
+        }
+        entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.path.cmp(&b.path)));
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+    use std::os::unix::fs::symlink;
+
+    /// A data root in a fresh temporary directory, next to a file outside it
+    struct Fixture {
+        dir: TempDir,
+        data_root: DataRoot,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir = TempDir::new(&format!("data-root-{}", name));
+            std::fs::create_dir_all(dir.path().join("root/sub")).unwrap();
+            std::fs::write(dir.path().join("root/a.csv"), "time,key,value\n").unwrap();
+            std::fs::write(dir.path().join("root/sub/b.csv"), "").unwrap();
+            std::fs::write(dir.path().join("secret.csv"), "").unwrap();
+            let data_root = DataRoot::new(dir.path().join("root")).unwrap();
+            Self { dir, data_root }
+        }
+    }
+
+    #[test]
+    fn resolves_paths_inside_the_root() {
+        let fixture = Fixture::new("inside");
+        let root = fixture.data_root.path();
+        assert_eq!(fixture.data_root.resolve("").unwrap(), root);
+        assert_eq!(fixture.data_root.resolve_file("sub/b.csv").unwrap(), root.join("sub/b.csv"));
+        assert_eq!(fixture.data_root.resolve_file("./a.csv").unwrap(), root.join("a.csv"));
+        assert_eq!(fixture.data_root.read_to_string("a.csv").unwrap(), "time,key,value\n");
+        assert_eq!(fixture.data_root.resolve_new("sub/new.rrd", "rrd").unwrap(), root.join("sub/new.rrd"));
+    }
+
+    #[test]
+    fn rejects_parent_and_absolute_paths() {
+        let fixture = Fixture::new("escape");
+        let outside = fixture.dir.path().join("secret.csv");
+        for path in ["../secret.csv", "sub/../../secret.csv", "sub/../a.csv", outside.to_str().unwrap(), "/etc/passwd"] {
+            for result in [fixture.data_root.resolve(path), fixture.data_root.resolve_new(path, "csv")] {
+                assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument, "{}", path);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_symlinks_leading_out() {
+        let fixture = Fixture::new("symlinks");
+        let root = fixture.data_root.path();
+        symlink(fixture.dir.path().join("secret.csv"), root.join("out.csv")).unwrap();
+        symlink(fixture.dir.path(), root.join("out")).unwrap();
+        symlink(root.join("a.csv"), root.join("in.csv")).unwrap();
+
+        let denied = |result: Result<PathBuf, Status>| result.unwrap_err().code() == tonic::Code::PermissionDenied;
+        assert!(denied(fixture.data_root.resolve_file("out.csv")));
+        assert!(denied(fixture.data_root.resolve("out/secret.csv")));
+        assert!(denied(fixture.data_root.resolve_new("out/new.rrd", "rrd")));
+        // Symlinks inside the root are fine to read
+        assert_eq!(fixture.data_root.resolve_file("in.csv").unwrap(), root.join("a.csv"));
+
+        let listed: Vec<String> = fixture.data_root.list("").unwrap().into_iter().map(|entry| entry.path).collect();
+        assert_eq!(listed, ["sub", "a.csv", "in.csv"]);
+    }
+
+    #[test]
+    fn resolve_new_refuses_to_write_through_symlinks() {
+        let fixture = Fixture::new("new-symlink");
+        let root = fixture.data_root.path();
+        symlink(fixture.dir.path().join("secret.csv"), root.join("out.rrd")).unwrap();
+        symlink(fixture.dir.path().join("missing.rrd"), root.join("dangling.rrd")).unwrap();
+        symlink(root.join("a.csv"), root.join("in.rrd")).unwrap();
+
+        for path in ["out.rrd", "dangling.rrd", "in.rrd"] {
+            assert_eq!(fixture.data_root.resolve_new(path, "rrd").unwrap_err().code(), tonic::Code::PermissionDenied, "{}", path);
+        }
+        std::fs::create_dir(root.join("dir.rrd")).unwrap();
+        assert_eq!(fixture.data_root.resolve_new("dir.rrd", "rrd").unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn resolve_new_only_writes_its_extension() {
+        let fixture = Fixture::new("new-extension");
+        let root = fixture.data_root.path();
+        std::fs::write(root.join("old.rrd"), "").unwrap();
+
+        // Data files can't be overwritten, whether they exist or not
+        for path in ["a.csv", "sub/b.csv", "new.csv", "sub", "rrd", "a.csv.rrd/x"] {
+            assert_eq!(fixture.data_root.resolve_new(path, "rrd").unwrap_err().code(), tonic::Code::InvalidArgument, "{}", path);
+        }
+        assert_eq!(std::fs::read_to_string(root.join("a.csv")).unwrap(), "time,key,value\n");
+        // Earlier exports may be
+        assert_eq!(fixture.data_root.resolve_new("old.rrd", "rrd").unwrap(), root.join("old.rrd"));
+    }
+
+    #[test]
+    fn missing_paths_and_directories() {
+        let fixture = Fixture::new("missing");
+        assert_eq!(fixture.data_root.resolve("nope.csv").unwrap_err().code(), tonic::Code::NotFound);
+        assert_eq!(fixture.data_root.resolve_new("nope/new.rrd", "rrd").unwrap_err().code(), tonic::Code::NotFound);
+        assert_eq!(fixture.data_root.resolve_file("sub").unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(fixture.data_root.resolve_file("").unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+}
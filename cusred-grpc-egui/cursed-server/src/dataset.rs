@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    String(String),
+}
+
+/// Samples per key and time in ms, the same layout cursed-egui's core keeps
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dataset {
+    pub data: BTreeMap<String, BTreeMap<u64, Value>>,
+}
+
+impl Dataset {
+    /// Parse `time,key,value` rows. Values that parse as numbers become
+    /// numbers, anything else a string with surrounding quotes removed.
+    pub fn from_csv(csv: &str) -> Result<Self, String> {
+        let mut dataset = Self::default();
+        for (index, line) in csv.lines().enumerate() {
+            if let Some((time, key, value)) = parse_row(line, index == 0)
+                .map_err(|e| format!("Line {}: {}", index + 1, e))?
+            {
+                dataset.insert(key, time, value);
+            }
+        }
+        Ok(dataset)
+    }
+
+    pub fn insert(&mut self, key: String, time: u64, value: Value) {
+        self.data.entry(key).or_default().insert(time, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.values().map(|samples| samples.len()).sum()
+    }
+
+    /// Latest number logged for `key` at or before `time`
+    pub fn number_at(&self, key: &str, time: u64) -> Option<f64> {
+        match self.data.get(key)?.range(..=time).next_back()? {
+            (_, Value::Number(number)) => Some(*number),
+            (_, Value::String(_)) => None,
+        }
+    }
+}
+
+/// One `time,key,value` row, `None` for blank lines and, when `first`, the
+/// header row
+pub fn parse_row(line: &str, first: bool) -> Result<Option<(u64, String, Value)>, String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parts = line.splitn(3, ',');
+    let (Some(time), Some(key), Some(value)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("expected time,key,value".to_string());
+    };
+    let time = match time.trim().parse::<u64>() {
+        Ok(time) => time,
+        Err(_) if first && time.trim() == "time" => return Ok(None),
+        Err(e) => return Err(format!("bad time {:?}: {}", time, e)),
+    };
+
+    let value = value.trim();
+    let value = match value.parse::<f64>() {
+        Ok(number) => Value::Number(number),
+        Err(_) => Value::String(value.trim_matches('"').to_string()),
+    };
+    Ok(Some((time, key.trim().to_string(), value)))
+}
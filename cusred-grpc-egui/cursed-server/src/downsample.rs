@@ -0,0 +1,165 @@
+/// Smallest and largest value of each of `max_points / 2` equally sized
+/// buckets, in time order, so spikes survive
+pub fn min_max(points: &[(u64, f64)], max_points: usize) -> Vec<(u64, f64)> {
+    if points.len() <= max_points {
+        return points.to_vec();
+    }
+    if max_points < 2 {
+        return evenly(points, max_points);
+    }
+
+    let buckets = max_points / 2;
+    let mut result = Vec::with_capacity(buckets * 2);
+    for bucket in 0..buckets {
+        let bucket = &points[bucket * points.len() / buckets..(bucket + 1) * points.len() / buckets];
+        let (mut min, mut max) = (0, 0);
+        for (index, (_, value)) in bucket.iter().enumerate() {
+            if *value < bucket[min].1 {
+                min = index;
+            }
+            if *value > bucket[max].1 {
+                max = index;
+            }
+        }
+        result.push(bucket[min.min(max)]);
+        if min != max {
+            result.push(bucket[min.max(max)]);
+        }
+    }
+    result
+}
+
+/// Largest-Triangle-Three-Buckets: the first and last point, and from each
+/// bucket in between the point spanning the largest triangle with the point
+/// kept before it and the average of the next bucket
+pub fn lttb(points: &[(u64, f64)], max_points: usize) -> Vec<(u64, f64)> {
+    if points.len() <= max_points {
+        return points.to_vec();
+    }
+    if max_points < 3 {
+        return evenly(points, max_points);
+    }
+
+    // Buckets between the first and last point
+    let buckets = max_points - 2;
+    let inner = &points[1..points.len() - 1];
+    let bucket = |index: usize| &inner[index * inner.len() / buckets..(index + 1) * inner.len() / buckets];
+
+    let mut result = Vec::with_capacity(max_points);
+    result.push(points[0]);
+    for index in 0..buckets {
+        let (next_time, next_value) = if index + 1 < buckets {
+            let next = bucket(index + 1);
+            let count = next.len() as f64;
+            (
+                next.iter().map(|(time, _)| *time as f64).sum::<f64>() / count,
+                next.iter().map(|(_, value)| *value).sum::<f64>() / count,
+            )
+        } else {
+            let (time, value) = points[points.len() - 1];
+            (time as f64, value)
+        };
+
+        let (kept_time, kept_value) = *result.last().unwrap();
+        let (kept_time, kept_value) = (kept_time as f64, kept_value);
+        let area = |(time, value): &(u64, f64)| {
+            ((kept_time - next_time) * (value - kept_value) - (kept_time - *time as f64) * (next_value - kept_value)).abs()
+        };
+        let chosen = bucket(index).iter()
+            .max_by(|a, b| area(a).total_cmp(&area(b)))
+            .unwrap();
+        result.push(*chosen);
+    }
+    result.push(points[points.len() - 1]);
+    result
+}
+
+/// `max_points` items spread evenly over `items`, the first one included
+pub fn evenly<T: Clone>(items: &[T], max_points: usize) -> Vec<T> {
+    if items.len() <= max_points {
+        return items.to_vec();
+    }
+    (0..max_points).map(|index| items[index * items.len() / max_points].clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A flat line at 0 with a spike up at 300 and down at 700
+    fn spiky() -> Vec<(u64, f64)> {
+        (0..1000u64)
+            .map(|time| (time, match time {
+                300 => 10.0,
+                700 => -10.0,
+                _ => 0.0,
+            }))
+            .collect()
+    }
+
+    fn in_time_order(points: &[(u64, f64)]) -> bool {
+        points.windows(2).all(|pair| pair[0].0 < pair[1].0)
+    }
+
+    #[test]
+    fn short_inputs_are_kept_as_they_are() {
+        let points = vec![(1, 1.0), (2, 5.0), (3, -1.0)];
+        for max_points in [3, 4, 100] {
+            assert_eq!(min_max(&points, max_points), points);
+            assert_eq!(lttb(&points, max_points), points);
+            assert_eq!(evenly(&points, max_points), points);
+        }
+        assert!(lttb(&[], 10).is_empty());
+        assert!(min_max(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn min_max_keeps_spikes() {
+        let points = spiky();
+        let downsampled = min_max(&points, 20);
+        assert!(downsampled.len() <= 20);
+        assert!(in_time_order(&downsampled));
+        assert!(downsampled.contains(&(300, 10.0)));
+        assert!(downsampled.contains(&(700, -10.0)));
+    }
+
+    #[test]
+    fn min_max_keeps_one_point_for_flat_buckets() {
+        let points: Vec<(u64, f64)> = (0..100).map(|time| (time, 1.0)).collect();
+        assert_eq!(min_max(&points, 10), [(0, 1.0), (20, 1.0), (40, 1.0), (60, 1.0), (80, 1.0)]);
+    }
+
+    #[test]
+    fn lttb_keeps_the_endpoints_and_spikes() {
+        let points = spiky();
+        let downsampled = lttb(&points, 20);
+        assert_eq!(downsampled.len(), 20);
+        assert_eq!(downsampled.first(), points.first());
+        assert_eq!(downsampled.last(), points.last());
+        assert!(in_time_order(&downsampled));
+        assert!(downsampled.contains(&(300, 10.0)));
+        assert!(downsampled.contains(&(700, -10.0)));
+    }
+
+    #[test]
+    fn lttb_with_a_single_inner_bucket() {
+        // The point furthest from the line between the endpoints wins
+        let points = [(0, 0.0), (1, 2.0), (2, -3.0), (3, 1.0), (4, 0.0)];
+        assert_eq!(lttb(&points, 3), [(0, 0.0), (2, -3.0), (4, 0.0)]);
+    }
+
+    #[test]
+    fn too_few_points_fall_back_to_evenly() {
+        let points = spiky();
+        assert_eq!(min_max(&points, 1), [(0, 0.0)]);
+        assert_eq!(lttb(&points, 2), [(0, 0.0), (500, 0.0)]);
+        assert!(lttb(&points, 0).is_empty());
+    }
+
+    #[test]
+    fn evenly_spreads_from_the_first_item() {
+        let items: Vec<u32> = (0..10).collect();
+        assert_eq!(evenly(&items, 5), [0, 2, 4, 6, 8]);
+        assert_eq!(evenly(&items, 3), [0, 3, 6]);
+    }
+}
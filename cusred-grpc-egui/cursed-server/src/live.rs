@@ -0,0 +1,243 @@
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use log::{info, warn};
+use tokio::sync::broadcast;
+
+use crate::dataset::{self, Dataset, Value};
+
+/// Samples a subscriber may fall behind by before its stream is ended
+const CHANNEL_CAPACITY: usize = 4096;
+
+/// Samples kept per key for subscribers asking for a start time
+const HISTORY_PER_KEY: usize = 10_000;
+
+/// How often followed files are checked for new rows
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub key: String,
+    pub time: u64,
+    pub value: Value,
+}
+
+/// Live samples, fanned out to every subscriber and kept for a while so
+/// subscribers can start in the past
+#[derive(Debug)]
+pub struct Live {
+    history: Mutex<Dataset>,
+    sender: broadcast::Sender<Arc<Sample>>,
+}
+
+/// Keys a subscriber wants. A topic matches itself and every key below it,
+/// `robot` matches `robot`, `robot/speed` and `robot.pose.x` but not
+/// `robots`. No topics match everything.
+#[derive(Debug, Clone, Default)]
+pub struct TopicFilter {
+    pub topics: Vec<String>,
+}
+
+impl TopicFilter {
+    pub fn matches(&self, key: &str) -> bool {
+        self.topics.is_empty() || self.topics.iter().any(|topic| {
+            key.strip_prefix(topic.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '.']))
+        })
+    }
+}
+
+impl Live {
+    pub fn new() -> Self {
+        Self {
+            history: Mutex::new(Dataset::default()),
+            sender: broadcast::channel(CHANNEL_CAPACITY).0,
+        }
+    }
+
+    pub fn publish(&self, sample: Sample) {
+        let mut history = self.history.lock().unwrap();
+        let samples = history.data.entry(sample.key.clone()).or_default();
+        samples.insert(sample.time, sample.value.clone());
+        if samples.len() > HISTORY_PER_KEY {
+            samples.pop_first();
+        }
+        // Sent under the lock so subscribers see each sample exactly once
+        let _ = self.sender.send(Arc::new(sample));
+    }
+
+    /// Kept samples of `key` from `start` to `end`, both included
+    pub fn range(&self, key: &str, start: u64, end: u64) -> Option<Vec<(u64, Value)>> {
+        let history = self.history.lock().unwrap();
+        let samples = history.data.get(key)?;
+        Some(samples.range(start..=end).map(|(time, value)| (*time, value.clone())).collect())
+    }
+
+    /// Kept samples at or after `start_time` in time order, oldest first,
+    /// and a receiver for everything published after them
+    pub fn subscribe(&self, filter: &TopicFilter, start_time: Option<u64>) -> (Vec<Sample>, broadcast::Receiver<Arc<Sample>>) {
+        let history = self.history.lock().unwrap();
+        let mut backlog = Vec::new();
+        if let Some(start_time) = start_time {
+            for (key, samples) in history.data.iter().filter(|(key, _)| filter.matches(key)) {
+                backlog.extend(samples.range(start_time..).map(|(time, value)| Sample {
+                    key: key.clone(),
+                    time: *time,
+                    value: value.clone(),
+                }));
+            }
+            backlog.sort_by_key(|sample| sample.time);
+        }
+        (backlog, self.sender.subscribe())
+    }
+}
+
+/// Publish rows appended to a `time,key,value` CSV file, starting with the
+/// rows already in it. A file that shrinks is read again from the start.
+pub fn follow_csv(live: Arc<Live>, path: PathBuf) {
+    std::thread::spawn(move || {
+        info!("Following {}", path.display());
+        let mut offset = 0;
+        let mut lines = Lines::default();
+        loop {
+            match read_from(&path, &mut offset) {
+                Ok(Some(bytes)) => {
+                    for (line_number, line) in lines.push(&bytes) {
+                        match dataset::parse_row(&line, line_number == 1) {
+                            Ok(Some((time, key, value))) => live.publish(Sample { key, time, value }),
+                            Ok(None) => {}
+                            Err(e) => warn!("{} line {}: {}", path.display(), line_number, e),
+                        }
+                    }
+                }
+                Ok(None) => {
+                    info!("{} was truncated, reading it again", path.display());
+                    offset = 0;
+                    lines = Lines::default();
+                }
+                Err(e) => warn!("Failed to read {}: {}", path.display(), e),
+            }
+            std::thread::sleep(FOLLOW_INTERVAL);
+        }
+    });
+}
+
+/// Splits the bytes read from a file into lines. Rows aren't published
+/// until their line is complete, so the rest is kept for the next read.
+#[derive(Debug, Default)]
+struct Lines {
+    partial: Vec<u8>,
+    line_number: usize,
+}
+
+impl Lines {
+    /// Lines completed by `bytes` with their 1-based line numbers
+    fn push(&mut self, bytes: &[u8]) -> Vec<(usize, String)> {
+        self.partial.extend_from_slice(bytes);
+        let mut lines = Vec::new();
+        while let Some(end) = self.partial.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=end).collect();
+            self.line_number += 1;
+            lines.push((self.line_number, String::from_utf8_lossy(&line).into_owned()));
+        }
+        lines
+    }
+}
+
+/// Bytes after `offset`, advancing it, or `None` if the file is now shorter
+fn read_from(path: &PathBuf, offset: &mut u64) -> std::io::Result<Option<Vec<u8>>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    if len < *offset {
+        return Ok(None);
+    }
+    file.seek(SeekFrom::Start(*offset))?;
+    let mut bytes = Vec::new();
+    file.take(len - *offset).read_to_end(&mut bytes)?;
+    *offset += bytes.len() as u64;
+    Ok(Some(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    fn filter(topics: &[&str]) -> TopicFilter {
+        TopicFilter { topics: topics.iter().map(|topic| topic.to_string()).collect() }
+    }
+
+    fn sample(key: &str, time: u64) -> Sample {
+        Sample { key: key.to_string(), time, value: Value::Number(time as f64) }
+    }
+
+    #[test]
+    fn topics_match_themselves_and_keys_below_them() {
+        let robot = filter(&["robot"]);
+        for key in ["robot", "robot/speed", "robot.pose.x"] {
+            assert!(robot.matches(key), "{}", key);
+        }
+        for key in ["robots", "robots/speed", "rob", "other/robot"] {
+            assert!(!robot.matches(key), "{}", key);
+        }
+        assert!(filter(&[]).matches("anything"));
+        let both = filter(&["a/b", "c"]);
+        assert!(both.matches("a/b/c") && both.matches("c.d"));
+        assert!(!both.matches("a") && !both.matches("a/bc"));
+    }
+
+    #[test]
+    fn subscribers_get_the_backlog_in_time_order_then_new_samples() {
+        let live = Live::new();
+        for (key, time) in [("robot/b", 30), ("robot/a", 10), ("robot/b", 20), ("robot/a", 40), ("other", 25), ("robot/a", 5)] {
+            live.publish(sample(key, time));
+        }
+
+        let (backlog, mut receiver) = live.subscribe(&filter(&["robot"]), Some(10));
+        let backlog: Vec<(&str, u64)> = backlog.iter().map(|sample| (sample.key.as_str(), sample.time)).collect();
+        assert_eq!(backlog, [("robot/a", 10), ("robot/b", 20), ("robot/b", 30), ("robot/a", 40)]);
+
+        live.publish(sample("robot/a", 50));
+        assert_eq!(*receiver.try_recv().unwrap(), sample("robot/a", 50));
+        assert!(receiver.try_recv().is_err());
+
+        // Without a start time only new samples are sent
+        let (backlog, _) = live.subscribe(&filter(&[]), None);
+        assert!(backlog.is_empty());
+    }
+
+    #[test]
+    fn reads_continue_from_the_offset_until_the_file_shrinks() {
+        let dir = TempDir::new("live-read-from");
+        let path = dir.path().join("follow.csv");
+        std::fs::write(&path, "0,a,1\n").unwrap();
+
+        let mut offset = 0;
+        assert_eq!(read_from(&path, &mut offset).unwrap().unwrap(), b"0,a,1\n");
+        assert_eq!(offset, 6);
+        assert_eq!(read_from(&path, &mut offset).unwrap().unwrap(), b"");
+
+        std::fs::write(&path, "0,a,1\n1,a,2\n").unwrap();
+        assert_eq!(read_from(&path, &mut offset).unwrap().unwrap(), b"1,a,2\n");
+        assert_eq!(offset, 12);
+
+        std::fs::write(&path, "0,b,1\n").unwrap();
+        assert_eq!(read_from(&path, &mut offset).unwrap(), None);
+        assert_eq!(offset, 12);
+    }
+
+    #[test]
+    fn partial_lines_wait_for_their_end() {
+        let mut lines = Lines::default();
+        assert_eq!(lines.push(b"time,key,val"), []);
+        assert_eq!(lines.push(b"ue\n0,a,"), [(1, "time,key,value\n".to_string())]);
+        assert_eq!(lines.push(b"1\n1,a,2\n2,a"), [(2, "0,a,1\n".to_string()), (3, "1,a,2\n".to_string())]);
+        assert_eq!(lines.push(b""), []);
+        assert_eq!(lines.push(b",3\n"), [(4, "2,a,3\n".to_string())]);
+    }
+}
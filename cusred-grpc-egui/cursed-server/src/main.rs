@@ -1,19 +1,63 @@
 
-use std::{sync::{Arc, Mutex}, time::Instant};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
-use tonic_web::GrpcWebLayer;
-use tonic::{transport::Server, Request, Response, Status};
-use log::{debug, info};
-use prost::Message;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{transport::Server, Request, Response, Status, Streaming};
+use log::{debug, info, warn};
+use cursed::csv_service_server::CsvServiceServer;
+
+mod data_root;
+mod dataset;
+mod downsample;
+mod live;
+mod query;
+mod rerun_export;
+#[cfg(test)]
+mod test_util;
+mod uploads;
 
 pub mod cursed {
-    tonic::include_proto!("cursed"); / The stri/ng specified here must match the proto package name
+    tonic::include_proto!("cursed"); // The string specified here must match the proto package name
+
+    pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("cursed_descriptor");
 }
 
 
+/// Used when `CURSED_DATA_ROOT` isn't set
+const DEFAULT_DATA_ROOT: &str = "data";
+
+/// How often the data root is checked for the health service
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Messages buffered per subscriber before the stream waits on the client
+const SUBSCRIBE_BUFFER: usize = 256;
+
+
 #[derive(Debug)]
 pub struct CSVServiceImpl {
+    data_root: data_root::DataRoot,
+    live: Arc<live::Live>,
+    uploads: uploads::Uploads,
+    datasets: query::DatasetCache,
+}
 
+fn data_point_msg(topic: &str, time: u64, value: &dataset::Value) -> cursed::DataPointMsg {
+    let value = match value {
+        dataset::Value::Number(number) => cursed::data_point_msg::Value::Scalar(*number),
+        dataset::Value::String(text) => cursed::data_point_msg::Value::Text(text.clone()),
+    };
+    cursed::DataPointMsg {
+        topic: topic.to_string(),
+        time,
+        value: Some(value),
+    }
+}
+
+impl From<&live::Sample> for cursed::DataPointMsg {
+    fn from(sample: &live::Sample) -> Self {
+        data_point_msg(&sample.key, sample.time, &sample.value)
+    }
 }
 
 
@@ -28,8 +72,7 @@ impl cursed::csv_service_server::CsvService for CSVServiceImpl {
         let inner = request.into_inner();
         info!("Got a request: {:?}",inner.clone());
         
-        let path = rfd::FileDialog::new().pick_file().unwrap();
-        let file = std::fs::read_to_string(path).unwrap();
+        let file = self.data_root.read_to_string(&inner.path)?;
 
         let reply = cursed::CsvResponse {
             csv_contents: file,
@@ -37,7 +80,175 @@ impl cursed::csv_service_server::CsvService for CSVServiceImpl {
         };
         Ok(Response::new(reply)) // Send back our formatted greeting
     }
-    
+
+    async fn export_rerun(
+        &self,
+        request: Request<cursed::ExportRerunRequest>,
+    ) -> Result<Response<cursed::ExportRerunResponse>, Status> {
+        let inner = request.into_inner();
+        info!("Got an export request: {:?}", inner);
+
+        if !inner.path.ends_with(".csv") {
+            return Err(Status::invalid_argument(format!("{:?} isn't a CSV, only CSVs can be exported", inner.path)));
+        }
+        let csv = self.data_root.read_to_string(&inner.path)?;
+        let dataset = dataset::Dataset::from_csv(&csv)
+            .map_err(Status::invalid_argument)?;
+
+        let viewer_addr = if inner.viewer_addr.is_empty() {
+            None
+        } else {
+            let addr: SocketAddr = inner.viewer_addr.parse()
+                .map_err(|e| Status::invalid_argument(format!("Bad viewer address {:?}: {}", inner.viewer_addr, e)))?;
+            // Clients may not make the server connect anywhere else
+            if !addr.ip().is_loopback() {
+                return Err(Status::permission_denied(format!("Viewer address {} isn't a loopback address", addr)));
+            }
+            Some(addr)
+        };
+        let rrd_path = if inner.rrd_path.is_empty() {
+            None
+        } else {
+            Some(self.data_root.resolve_new(&inner.rrd_path, "rrd")?)
+        };
+        let options = rerun_export::ExportOptions {
+            rrd_path,
+            viewer_addr,
+        };
+        if options.rrd_path.is_none() && options.viewer_addr.is_none() {
+            return Err(Status::invalid_argument("Set rrd_path, viewer_addr or both"));
+        }
+
+        // Logging and flushing block, keep them off the async workers
+        let samples = dataset.len() as u64;
+        tokio::task::spawn_blocking(move || rerun_export::export(&dataset, &options))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .map_err(Status::internal)?;
+
+        Ok(Response::new(cursed::ExportRerunResponse {
+            was_successfull: true,
+            samples,
+        }))
+    }
+
+    async fn list_files(
+        &self,
+        request: Request<cursed::ListFilesRequest>,
+    ) -> Result<Response<cursed::ListFilesResponse>, Status> {
+        let inner = request.into_inner();
+        debug!("Got a list request: {:?}", inner);
+
+        let files = self.data_root.list(&inner.path)?;
+        Ok(Response::new(cursed::ListFilesResponse { files }))
+    }
+
+    type SubscribeStream = ReceiverStream<Result<cursed::DataPointMsg, Status>>;
+
+    async fn subscribe(
+        &self,
+        request: Request<cursed::SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let inner = request.into_inner();
+        info!("Got a subscription: {:?}", inner);
+
+        let filter = live::TopicFilter { topics: inner.topics };
+        let (backlog, mut receiver) = self.live.subscribe(&filter, inner.start_time);
+        let (sender, stream) = mpsc::channel(SUBSCRIBE_BUFFER);
+        tokio::spawn(async move {
+            for sample in &backlog {
+                if sender.send(Ok(sample.into())).await.is_err() {
+                    return;
+                }
+            }
+            loop {
+                let sample = match receiver.recv().await {
+                    Ok(sample) => sample,
+                    Err(RecvError::Lagged(skipped)) => {
+                        // End the stream rather than leave a silent gap in it
+                        warn!("Subscriber lagged, skipped {} samples", skipped);
+                        let message = format!("Fell {} samples behind, subscribe again to resume", skipped);
+                        let _ = sender.send(Err(Status::resource_exhausted(message))).await;
+                        return;
+                    }
+                    Err(RecvError::Closed) => return,
+                };
+                if filter.matches(&sample.key) && sender.send(Ok(sample.as_ref().into())).await.is_err() {
+                    // Client went away
+                    return;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(stream)))
+    }
+
+    async fn upload(
+        &self,
+        request: Request<Streaming<cursed::UploadChunk>>,
+    ) -> Result<Response<cursed::UploadResponse>, Status> {
+        let mut chunks = request.into_inner();
+
+        let header = match chunks.message().await?.and_then(|chunk| chunk.chunk) {
+            Some(cursed::upload_chunk::Chunk::Header(header)) => header,
+            _ => return Err(Status::invalid_argument("An upload must start with a header")),
+        };
+        info!("Got an upload: {:?}", header);
+
+        let mut upload = self.uploads.begin(&header.file_name).await?;
+        while let Some(chunk) = chunks.message().await? {
+            match chunk.chunk {
+                Some(cursed::upload_chunk::Chunk::Data(data)) => upload.write(&data).await?,
+                _ => return Err(Status::invalid_argument("Expected only data after the header")),
+            }
+        }
+        let dataset = self.uploads.finish(upload).await?;
+
+        Ok(Response::new(cursed::UploadResponse { dataset: Some(dataset) }))
+    }
+
+    async fn list_datasets(
+        &self,
+        _request: Request<cursed::ListDatasetsRequest>,
+    ) -> Result<Response<cursed::ListDatasetsResponse>, Status> {
+        Ok(Response::new(cursed::ListDatasetsResponse { datasets: self.uploads.list() }))
+    }
+
+    async fn query_range(
+        &self,
+        request: Request<cursed::QueryRangeRequest>,
+    ) -> Result<Response<cursed::QueryRangeResponse>, Status> {
+        let inner = request.into_inner();
+        debug!("Got a range query: {:?}", inner);
+
+        let t_end = if inner.t_end == 0 { u64::MAX } else { inner.t_end };
+        if inner.t_start > t_end {
+            return Err(Status::invalid_argument(format!("t_start {} is after t_end {}", inner.t_start, t_end)));
+        }
+        let max_points = if inner.max_points == 0 { query::DEFAULT_MAX_POINTS } else { inner.max_points as usize };
+        let downsampling = cursed::Downsampling::try_from(inner.downsampling)
+            .map_err(|_| Status::invalid_argument(format!("Unknown downsampling {}", inner.downsampling)))?;
+
+        let samples = if inner.path.is_empty() {
+            self.live.range(&inner.topic, inner.t_start, t_end)
+        } else {
+            let resolved = self.data_root.resolve_file(&inner.path)?;
+            let dataset = self.datasets.load(&inner.path, resolved).await?;
+            dataset.data.get(&inner.topic).map(|samples| {
+                samples.range(inner.t_start..=t_end)
+                    .map(|(time, value)| (*time, value.clone()))
+                    .collect()
+            })
+        };
+        let samples = samples.ok_or_else(|| Status::not_found(format!("No topic {:?}", inner.topic)))?;
+
+        let total_points = samples.len() as u64;
+        let points = query::downsample(samples, max_points, downsampling)
+            .iter()
+            .map(|(time, value)| data_point_msg(&inner.topic, *time, value))
+            .collect();
+        Ok(Response::new(cursed::QueryRangeResponse { points, total_points }))
+    }
 }
 
 
@@ -48,15 +259,71 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     pretty_env_logger::init();
     let addr = "0.0.0.0:5050".parse()?;
 
+    let data_root = std::env::var("CURSED_DATA_ROOT").unwrap_or_else(|_| DEFAULT_DATA_ROOT.to_string());
+    let data_root = data_root::DataRoot::new(&data_root)
+        .map_err(|e| format!("Bad data root {:?}: {}", data_root, e))?;
+    info!("Serving files from {}", data_root.path().display());
+
+    // Comma separated CSV files in the data root to stream to subscribers
+    let live = Arc::new(live::Live::new());
+    for path in std::env::var("CURSED_FOLLOW").unwrap_or_default().split(',').filter(|path| !path.is_empty()) {
+        let resolved = data_root.resolve_file(path.trim())
+            .map_err(|e| format!("Can't follow {}", e.message()))?;
+        live::follow_csv(live.clone(), resolved);
+    }
+
+    let uploads = uploads::Uploads::open(&data_root)
+        .map_err(|e| format!("Failed to index uploads: {}", e))?;
+
+    // CsvService is only serving while it can read the data root
+    let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+    let health_root = data_root.clone();
+    tokio::spawn(async move {
+        let mut serving = None;
+        loop {
+            let readable = health_root.check_readable();
+            if serving != Some(readable.is_ok()) {
+                match &readable {
+                    Ok(()) => {
+                        info!("Data root is readable, serving");
+                        health_reporter.set_serving::<CsvServiceServer<CSVServiceImpl>>().await;
+                    }
+                    Err(e) => {
+                        warn!("Data root {} isn't readable, not serving: {}", health_root.path().display(), e);
+                        health_reporter.set_not_serving::<CsvServiceServer<CSVServiceImpl>>().await;
+                    }
+                }
+                serving = Some(readable.is_ok());
+            }
+            tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        }
+    });
+
+    // v1alpha too, many tools such as grpcurl still ask for it
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(cursed::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1()?;
+    let reflection_service_v1alpha = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(cursed::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1alpha()?;
+
     let service = CSVServiceImpl{
-        
+        data_root,
+        live,
+        uploads,
+        datasets: query::DatasetCache::default(),
     };
-    let service = cursed::csv_service_server::CsvServiceServer::new(service);
+    let service = CsvServiceServer::new(service);
     let service = tonic_web::enable(service);
     info!("Server listening on {}", addr);
     Server::builder()
         .accept_http1(true)
         .add_service(service)
+        .add_service(health_service)
+        .add_service(reflection_service)
+        .add_service(reflection_service_v1alpha)
         .serve(addr)
         .await?;
 
@@ -0,0 +1,153 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Instant, SystemTime},
+};
+
+use log::info;
+use tonic::Status;
+
+use crate::{
+    cursed::Downsampling,
+    data_root::io_status,
+    dataset::{Dataset, Value},
+    downsample,
+};
+
+/// Used when a query doesn't set `max_points`
+pub const DEFAULT_MAX_POINTS: usize = 2000;
+
+/// Parsed CSVs kept around, so zooming doesn't parse the file again
+const CACHED_DATASETS: usize = 8;
+
+/// A parsed CSV and the file version it was parsed from
+#[derive(Debug)]
+struct CachedDataset {
+    modified: SystemTime,
+    last_used: Instant,
+    dataset: Arc<Dataset>,
+}
+
+/// Parsed CSVs by path, dropped when the file changes
+#[derive(Debug, Default)]
+pub struct DatasetCache {
+    datasets: Mutex<HashMap<PathBuf, CachedDataset>>,
+}
+
+impl DatasetCache {
+    /// The dataset in the CSV at `resolved`, `path` being what the client asked for
+    pub async fn load(&self, path: &str, resolved: PathBuf) -> Result<Arc<Dataset>, Status> {
+        let modified = std::fs::metadata(&resolved)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| io_status(path, e))?;
+        if let Some(cached) = self.datasets.lock().unwrap().get_mut(&resolved) {
+            if cached.modified == modified {
+                cached.last_used = Instant::now();
+                return Ok(cached.dataset.clone());
+            }
+        }
+
+        let (file, path) = (resolved.clone(), path.to_string());
+        let dataset = tokio::task::spawn_blocking(move || {
+            let csv = std::fs::read_to_string(&file).map_err(|e| io_status(&path, e))?;
+            Dataset::from_csv(&csv).map_err(|e| Status::invalid_argument(format!("{}: {}", path, e)))
+        })
+            .await
+            .map_err(|e| Status::internal(e.to_string()))??;
+        info!("Loaded {} samples from {}", dataset.len(), resolved.display());
+
+        let dataset = Arc::new(dataset);
+        let mut datasets = self.datasets.lock().unwrap();
+        if datasets.len() >= CACHED_DATASETS && !datasets.contains_key(&resolved) {
+            // Evict the least recently used file
+            let oldest = datasets.iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(path, _)| path.clone());
+            if let Some(oldest) = oldest {
+                datasets.remove(&oldest);
+            }
+        }
+        datasets.insert(resolved, CachedDataset { modified, last_used: Instant::now(), dataset: dataset.clone() });
+        Ok(dataset)
+    }
+}
+
+/// Samples of a window no more than `max_points` long. Numbers are
+/// downsampled with `downsampling`, strings picked evenly.
+pub fn downsample(samples: Vec<(u64, Value)>, max_points: usize, downsampling: Downsampling) -> Vec<(u64, Value)> {
+    if samples.len() <= max_points {
+        return samples;
+    }
+
+    let numbers: Vec<(u64, f64)> = samples.iter()
+        .filter_map(|(time, value)| match value {
+            Value::Number(number) => Some((*time, *number)),
+            Value::String(_) => None,
+        })
+        .collect();
+    if numbers.is_empty() {
+        return downsample::evenly(&samples, max_points);
+    }
+    // A topic that mixes both is plotted as numbers
+    let numbers = match downsampling {
+        Downsampling::MinMax => downsample::min_max(&numbers, max_points),
+        Downsampling::Lttb => downsample::lttb(&numbers, max_points),
+    };
+    numbers.into_iter().map(|(time, number)| (time, Value::Number(number))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsample_picks_numbers_or_strings() {
+        let numbers: Vec<(u64, Value)> = (0..100).map(|time| (time, Value::Number(time as f64))).collect();
+        assert_eq!(downsample(numbers.clone(), 100, Downsampling::Lttb), numbers);
+        let downsampled = downsample(numbers, 10, Downsampling::Lttb);
+        assert_eq!(downsampled.len(), 10);
+        assert_eq!(downsampled[9], (99, Value::Number(99.0)));
+
+        let strings: Vec<(u64, Value)> = (0..100).map(|time| (time, Value::String(time.to_string()))).collect();
+        let downsampled = downsample(strings, 4, Downsampling::MinMax);
+        assert_eq!(downsampled.iter().map(|(time, _)| *time).collect::<Vec<_>>(), [0, 25, 50, 75]);
+
+        // Mixed topics are plotted as their numbers
+        let mixed: Vec<(u64, Value)> = (0..100)
+            .map(|time| (time, if time % 2 == 0 { Value::Number(1.0) } else { Value::String("x".to_string()) }))
+            .collect();
+        assert!(downsample(mixed, 10, Downsampling::MinMax).iter().all(|(_, value)| *value == Value::Number(1.0)));
+    }
+
+    #[tokio::test]
+    async fn cache_evicts_the_least_recently_used_dataset() {
+        let dir = std::env::temp_dir().join(format!("cursed-query-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths: Vec<PathBuf> = (0..=CACHED_DATASETS)
+            .map(|index| {
+                let path = dir.join(format!("{}.csv", index));
+                std::fs::write(&path, format!("{},key,1\n", index)).unwrap();
+                path
+            })
+            .collect();
+
+        let cache = DatasetCache::default();
+        let mut loaded = Vec::new();
+        for path in &paths[..CACHED_DATASETS] {
+            loaded.push(cache.load("", path.clone()).await.unwrap());
+        }
+        // The first file was loaded first but used last
+        let first = cache.load("", paths[0].clone()).await.unwrap();
+        assert!(Arc::ptr_eq(&first, &loaded[0]));
+
+        cache.load("", paths[CACHED_DATASETS].clone()).await.unwrap();
+        let datasets = cache.datasets.lock().unwrap();
+        assert_eq!(datasets.len(), CACHED_DATASETS);
+        assert!(datasets.contains_key(&paths[0]));
+        assert!(!datasets.contains_key(&paths[1]));
+        drop(datasets);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
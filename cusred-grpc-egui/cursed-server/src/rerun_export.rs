@@ -0,0 +1,207 @@
+use std::{collections::BTreeMap, net::SocketAddr, path::PathBuf, time::Duration};
+
+use log::info;
+use rerun::{RecordingStream, RecordingStreamBuilder, RecordingStreamResult};
+
+use crate::dataset::{Dataset, Value};
+
+const APPLICATION_ID: &str = "cursed-server";
+
+/// All samples are logged on this timeline, in seconds
+const TIMELINE: &str = "time";
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Write an `.rrd` file here
+    pub rrd_path: Option<PathBuf>,
+    /// Stream to a Rerun viewer listening here, usually `127.0.0.1:9876`
+    pub viewer_addr: Option<SocketAddr>,
+}
+
+/// Log a dataset to every sink in `options`:
+///
+/// - numbers as `Scalar` time series on their key
+/// - strings as `TextLog` entries on their key
+/// - keys ending in `position.{x,y,z}` as a `Transform3D` on the part before
+///   `position`, rotated by `orientation.{x,y,z,w}` (a quaternion) or
+///   `rotation.{x,y,z}` (roll, pitch, yaw in rad) when those keys exist.
+///   Parts may be separated by `.` or `/`.
+pub fn export(dataset: &Dataset, options: &ExportOptions) -> Result<(), String> {
+    if let Some(path) = &options.rrd_path {
+        let rec = RecordingStreamBuilder::new(APPLICATION_ID)
+            .save(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        log_dataset(&rec, dataset).map_err(|e| e.to_string())?;
+        rec.flush_blocking();
+        info!("Wrote {} samples to {}", dataset.len(), path.display());
+    }
+
+    if let Some(addr) = options.viewer_addr {
+        let rec = RecordingStreamBuilder::new(APPLICATION_ID)
+            .connect_tcp_opts(addr, Some(Duration::from_secs(2)))
+            .map_err(|e| format!("Failed to connect to Rerun viewer at {}: {}", addr, e))?;
+        log_dataset(&rec, dataset).map_err(|e| e.to_string())?;
+        rec.flush_blocking();
+        info!("Streamed {} samples to {}", dataset.len(), addr);
+    }
+    Ok(())
+}
+
+pub fn log_dataset(rec: &RecordingStream, dataset: &Dataset) -> RecordingStreamResult<()> {
+    for (key, samples) in &dataset.data {
+        for (time, value) in samples {
+            rec.set_time_seconds(TIMELINE, *time as f64 / 1000.0);
+            match value {
+                Value::Number(number) => rec.log(key.as_str(), &rerun::Scalar::new(*number))?,
+                Value::String(text) => rec.log(key.as_str(), &rerun::TextLog::new(text.as_str()))?,
+            }
+        }
+    }
+
+    for (entity, fields) in pose_fields(dataset) {
+        // A new transform whenever any part of the pose changes
+        let mut times: Vec<u64> = fields.values()
+            .flat_map(|key| dataset.data[*key].keys().copied())
+            .collect();
+        times.sort_unstable();
+        times.dedup();
+
+        let number = |group, axis, time| {
+            fields.get(&(group, axis))
+                .and_then(|key| dataset.number_at(key, time))
+                .unwrap_or(0.0)
+        };
+        for time in times {
+            let translation = [
+                number("position", "x", time) as f32,
+                number("position", "y", time) as f32,
+                number("position", "z", time) as f32,
+            ];
+            let [x, y, z, w] = if fields.contains_key(&("orientation", "w")) {
+                [
+                    number("orientation", "x", time),
+                    number("orientation", "y", time),
+                    number("orientation", "z", time),
+                    number("orientation", "w", time),
+                ]
+            } else {
+                quaternion_from_euler(
+                    number("rotation", "x", time),
+                    number("rotation", "y", time),
+                    number("rotation", "z", time),
+                )
+            };
+            let rotation = rerun::Quaternion::from_xyzw([x as f32, y as f32, z as f32, w as f32]);
+
+            rec.set_time_seconds(TIMELINE, time as f64 / 1000.0);
+            rec.log(entity, &rerun::Transform3D::from_translation_rotation(translation, rotation))?;
+        }
+    }
+    Ok(())
+}
+
+/// Keys that are part of a pose, grouped by the entity the pose belongs to
+/// and keyed by group (`position`, `orientation`, `rotation`) and axis.
+/// Entities without all of `position.{x,y,z}` are left out.
+fn pose_fields(dataset: &Dataset) -> BTreeMap<&str, BTreeMap<(&'static str, &'static str), &str>> {
+    let mut poses: BTreeMap<&str, BTreeMap<_, &str>> = BTreeMap::new();
+    for key in dataset.data.keys() {
+        let Some((rest, axis)) = key.rsplit_once(['.', '/']) else {
+            continue;
+        };
+        let Some((entity, group)) = rest.rsplit_once(['.', '/']) else {
+            continue;
+        };
+        let field = match (group, axis) {
+            ("position", "x") => ("position", "x"),
+            ("position", "y") => ("position", "y"),
+            ("position", "z") => ("position", "z"),
+            ("orientation", "x") => ("orientation", "x"),
+            ("orientation", "y") => ("orientation", "y"),
+            ("orientation", "z") => ("orientation", "z"),
+            ("orientation", "w") => ("orientation", "w"),
+            ("rotation", "x") => ("rotation", "x"),
+            ("rotation", "y") => ("rotation", "y"),
+            ("rotation", "z") => ("rotation", "z"),
+            _ => continue,
+        };
+        poses.entry(entity).or_default().insert(field, key.as_str());
+    }
+    poses.retain(|_, fields| ["x", "y", "z"].iter().all(|axis| fields.contains_key(&("position", *axis))));
+    poses
+}
+
+/// `[x, y, z, w]` for intrinsic Z-Y-X (yaw, pitch, roll) Euler angles
+fn quaternion_from_euler(roll: f64, pitch: f64, yaw: f64) -> [f64; 4] {
+    let (sr, cr) = (roll / 2.0).sin_cos();
+    let (sp, cp) = (pitch / 2.0).sin_cos();
+    let (sy, cy) = (yaw / 2.0).sin_cos();
+    [
+        sr * cp * cy - cr * sp * sy,
+        cr * sp * cy + sr * cp * sy,
+        cr * cp * sy - sr * sp * cy,
+        cr * cp * cy + sr * sp * sy,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dataset(keys: &[&str]) -> Dataset {
+        let mut dataset = Dataset::default();
+        for key in keys {
+            dataset.insert(key.to_string(), 0, Value::Number(0.0));
+        }
+        dataset
+    }
+
+    fn assert_close(actual: [f64; 4], expected: [f64; 4]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-12, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn pose_fields_are_grouped_by_entity() {
+        let dataset = dataset(&[
+            "robot/pose/position/x", "robot/pose/position/y", "robot/pose/position/z",
+            "robot/pose/rotation/z",
+            "arm.tool.position.x", "arm.tool.position.y", "arm.tool.position.z",
+            "arm.tool.orientation.w",
+            "robot/speed", "robot/pose/position/w", "position.x",
+        ]);
+        let poses = pose_fields(&dataset);
+
+        assert_eq!(poses.keys().copied().collect::<Vec<_>>(), ["arm.tool", "robot/pose"]);
+        let robot = &poses["robot/pose"];
+        assert_eq!(robot.len(), 4);
+        assert_eq!(robot[&("position", "x")], "robot/pose/position/x");
+        assert_eq!(robot[&("rotation", "z")], "robot/pose/rotation/z");
+        let arm = &poses["arm.tool"];
+        assert_eq!(arm.len(), 4);
+        assert_eq!(arm[&("orientation", "w")], "arm.tool.orientation.w");
+    }
+
+    #[test]
+    fn poses_without_a_full_position_are_skipped() {
+        let dataset = dataset(&[
+            "flat/position/x", "flat/position/y", "flat/rotation/z",
+            "full/position/x", "full/position/y", "full/position/z",
+        ]);
+        assert_eq!(pose_fields(&dataset).keys().copied().collect::<Vec<_>>(), ["full"]);
+    }
+
+    #[test]
+    fn quaternions_match_known_euler_angles() {
+        use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+
+        assert_close(quaternion_from_euler(0.0, 0.0, 0.0), [0.0, 0.0, 0.0, 1.0]);
+        assert_close(quaternion_from_euler(FRAC_PI_2, 0.0, 0.0), [FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2]);
+        assert_close(quaternion_from_euler(0.0, FRAC_PI_2, 0.0), [0.0, FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2]);
+        assert_close(quaternion_from_euler(0.0, 0.0, FRAC_PI_2), [0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2]);
+        assert_close(quaternion_from_euler(0.0, 0.0, PI), [0.0, 0.0, 1.0, 0.0]);
+        // Yaw then roll: a quarter turn about z followed by one about the new x
+        assert_close(quaternion_from_euler(FRAC_PI_2, 0.0, FRAC_PI_2), [0.5, 0.5, 0.5, 0.5]);
+    }
+}
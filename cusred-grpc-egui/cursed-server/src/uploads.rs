@@ -0,0 +1,385 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::{info, warn};
+use tokio::io::AsyncWriteExt;
+use tonic::Status;
+
+use crate::{
+    cursed::{DatasetFormat, DatasetInfo},
+    data_root::{io_status, DataRoot},
+    dataset::Dataset,
+};
+
+/// Directory in the data root uploads are stored in, one directory each
+const UPLOADS_DIR: &str = "uploads";
+
+/// Larger uploads are refused
+const MAX_UPLOAD_BYTES: u64 = 1 << 30;
+
+/// Uploaded datasets, stored as `uploads/<id>/<file name>` in the data root
+#[derive(Debug)]
+pub struct Uploads {
+    dir: PathBuf,
+    datasets: Mutex<BTreeMap<String, DatasetInfo>>,
+    /// Numbers the part files of uploads started in the same nanosecond
+    next_part: AtomicU64,
+}
+
+/// An upload being received, written to a hidden file until it's complete
+#[derive(Debug)]
+pub struct Upload {
+    file: tokio::fs::File,
+    part_path: PathBuf,
+    file_name: String,
+    format: DatasetFormat,
+    size_bytes: u64,
+}
+
+fn format_for(file_name: &str) -> Option<DatasetFormat> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    match extension.to_ascii_lowercase().as_str() {
+        "csv" => Some(DatasetFormat::Csv),
+        "arrow" | "feather" => Some(DatasetFormat::ArrowFile),
+        "arrows" => Some(DatasetFormat::ArrowStream),
+        "parquet" => Some(DatasetFormat::Parquet),
+        "mcap" => Some(DatasetFormat::Mcap),
+        _ => None,
+    }
+}
+
+/// Whether uploads of `format` are accepted. The server can't read Arrow,
+/// Parquet or MCAP files yet, so it can't check or index them either.
+fn readable(format: DatasetFormat) -> bool {
+    format == DatasetFormat::Csv
+}
+
+/// Parse `path` in full and describe it
+fn validate(path: &Path, format: DatasetFormat, info: &mut DatasetInfo) -> io::Result<()> {
+    if !readable(format) {
+        return Err(io::Error::new(io::ErrorKind::Unsupported, format!("{} files can't be read", format.as_str_name())));
+    }
+    let dataset = Dataset::from_csv(&std::fs::read_to_string(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    info.samples = dataset.len() as u64;
+    info.keys = dataset.data.keys().cloned().collect();
+    let times = dataset.data.values().flat_map(|samples| samples.keys().copied());
+    info.start_time = times.clone().min().unwrap_or(0);
+    info.end_time = times.max().unwrap_or(0);
+    Ok(())
+}
+
+impl Uploads {
+    /// Index the uploads already in the data root, dropping unfinished ones
+    pub fn open(data_root: &DataRoot) -> io::Result<Self> {
+        let dir = data_root.path().join(UPLOADS_DIR);
+        let mut datasets = BTreeMap::new();
+        if dir.is_dir() {
+            for entry in std::fs::read_dir(&dir)? {
+                let entry = entry?;
+                let id = entry.file_name().to_string_lossy().into_owned();
+                if id.starts_with('.') {
+                    warn!("Removing unfinished upload {}", entry.path().display());
+                    if let Err(e) = std::fs::remove_file(entry.path()) {
+                        warn!("Failed to remove {}: {}", entry.path().display(), e);
+                    }
+                    continue;
+                }
+                match Self::index(&entry.path(), &id) {
+                    Ok(info) => {
+                        datasets.insert(id, info);
+                    }
+                    Err(e) => warn!("Not indexing {}: {}", entry.path().display(), e),
+                }
+            }
+        }
+        info!("Indexed {} uploaded datasets", datasets.len());
+        Ok(Self { dir, datasets: Mutex::new(datasets), next_part: AtomicU64::new(0) })
+    }
+
+    /// Describe the single file in an upload's directory
+    fn index(dir: &Path, id: &str) -> io::Result<DatasetInfo> {
+        let mut files = std::fs::read_dir(dir)?;
+        let (Some(file), None) = (files.next().transpose()?, files.next()) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "expected one file"));
+        };
+        let file_name = file.file_name().to_string_lossy().into_owned();
+        let format = format_for(&file_name)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unknown format"))?;
+        let mut info = DatasetInfo {
+            id: id.to_string(),
+            path: format!("{}/{}/{}", UPLOADS_DIR, id, file_name),
+            format: format as i32,
+            size_bytes: file.metadata()?.len(),
+            ..Default::default()
+        };
+        validate(&file.path(), format, &mut info)?;
+        Ok(info)
+    }
+
+    pub fn list(&self) -> Vec<DatasetInfo> {
+        self.datasets.lock().unwrap().values().cloned().collect()
+    }
+
+    pub async fn begin(&self, file_name: &str) -> Result<Upload, Status> {
+        // Only the name is kept, whatever directories the client had it in
+        let file_name = Path::new(file_name).file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .filter(|name| !name.starts_with('.'))
+            .ok_or_else(|| Status::invalid_argument(format!("Bad file name {:?}", file_name)))?;
+        let format = format_for(&file_name).ok_or_else(|| Status::invalid_argument(format!(
+            "{:?} isn't a .csv, .arrow, .arrows, .parquet or .mcap file", file_name
+        )))?;
+        if !readable(format) {
+            return Err(Status::unimplemented(format!("{:?} can't be uploaded yet, only CSVs can", file_name)));
+        }
+
+        tokio::fs::create_dir_all(&self.dir).await.map_err(|e| io_status(UPLOADS_DIR, e))?;
+        // Never opens another upload's file, or one another process made
+        loop {
+            let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+            let part = self.next_part.fetch_add(1, Ordering::Relaxed);
+            let part_path = self.dir.join(format!(".{}-{}.part", nanos, part));
+            match tokio::fs::OpenOptions::new().write(true).create_new(true).open(&part_path).await {
+                Ok(file) => return Ok(Upload { file, part_path, file_name, format, size_bytes: 0 }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(io_status(UPLOADS_DIR, e)),
+            }
+        }
+    }
+
+    /// Validate a complete upload, then store and index it
+    pub async fn finish(&self, mut upload: Upload) -> Result<DatasetInfo, Status> {
+        upload.file.flush().await.map_err(|e| io_status(&upload.file_name, e))?;
+        upload.file.sync_all().await.map_err(|e| io_status(&upload.file_name, e))?;
+
+        let mut info = DatasetInfo {
+            format: upload.format as i32,
+            size_bytes: upload.size_bytes,
+            ..Default::default()
+        };
+        let (part_path, format) = (upload.part_path.clone(), upload.format);
+        let info = tokio::task::spawn_blocking(move || {
+            validate(&part_path, format, &mut info).map(|_| info)
+        })
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .map_err(|e| io_status(&upload.file_name, e))?;
+
+        let (id, dir) = self.create_dataset_dir().await?;
+        tokio::fs::rename(&upload.part_path, dir.join(&upload.file_name)).await
+            .map_err(|e| io_status(&upload.file_name, e))?;
+        let info = DatasetInfo {
+            path: format!("{}/{}/{}", UPLOADS_DIR, id, upload.file_name),
+            id: id.clone(),
+            ..info
+        };
+        info!("Stored {} as dataset {}", info.path, id);
+        self.datasets.lock().unwrap().insert(id, info.clone());
+        Ok(info)
+    }
+
+    /// A new directory named by the upload time in ms
+    async fn create_dataset_dir(&self) -> Result<(String, PathBuf), Status> {
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+        for attempt in 0.. {
+            let id = if attempt == 0 { millis.to_string() } else { format!("{}-{}", millis, attempt) };
+            let dir = self.dir.join(&id);
+            match tokio::fs::create_dir(&dir).await {
+                Ok(()) => return Ok((id, dir)),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(io_status(UPLOADS_DIR, e)),
+            }
+        }
+        unreachable!()
+    }
+}
+
+impl Upload {
+    pub async fn write(&mut self, data: &[u8]) -> Result<(), Status> {
+        self.size_bytes += data.len() as u64;
+        if self.size_bytes > MAX_UPLOAD_BYTES {
+            return Err(Status::resource_exhausted(format!(
+                "{} is larger than {} bytes", self.file_name, MAX_UPLOAD_BYTES
+            )));
+        }
+        self.file.write_all(data).await.map_err(|e| io_status(&self.file_name, e))
+    }
+}
+
+impl Drop for Upload {
+    /// Unfinished and rejected uploads aren't kept
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.part_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    /// An empty data root in a fresh temporary directory
+    struct Fixture {
+        dir: TempDir,
+        data_root: DataRoot,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir = TempDir::new(&format!("uploads-{}", name));
+            let data_root = DataRoot::new(dir.path()).unwrap();
+            Self { dir, data_root }
+        }
+
+        fn uploads_dir(&self) -> PathBuf {
+            self.dir.path().join(UPLOADS_DIR)
+        }
+
+        /// Names in the uploads directory, sorted
+        fn entries(&self) -> Vec<String> {
+            let mut entries: Vec<String> = std::fs::read_dir(self.uploads_dir()).unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            entries.sort();
+            entries
+        }
+    }
+
+    fn validated(fixture: &Fixture, name: &str, bytes: &[u8]) -> io::Result<DatasetInfo> {
+        let path = fixture.dir.path().join(name);
+        std::fs::write(&path, bytes).unwrap();
+        let mut info = DatasetInfo::default();
+        validate(&path, format_for(name).unwrap(), &mut info).map(|_| info)
+    }
+
+    #[test]
+    fn formats_follow_the_extension() {
+        assert_eq!(format_for("a.csv"), Some(DatasetFormat::Csv));
+        assert_eq!(format_for("a.FEATHER"), Some(DatasetFormat::ArrowFile));
+        assert_eq!(format_for("a.b.arrows"), Some(DatasetFormat::ArrowStream));
+        assert_eq!(format_for("a.parquet"), Some(DatasetFormat::Parquet));
+        assert_eq!(format_for("a.mcap"), Some(DatasetFormat::Mcap));
+        assert_eq!(format_for("a.txt"), None);
+        assert_eq!(format_for("csv"), None);
+    }
+
+    #[test]
+    fn csvs_are_parsed_in_full() {
+        let fixture = Fixture::new("csv");
+        let info = validated(&fixture, "a.csv", b"time,key,value\n10,speed,1.5\n30,mode,idle\n20,speed,2\n").unwrap();
+        assert_eq!(info.samples, 3);
+        assert_eq!(info.keys, ["mode", "speed"]);
+        assert_eq!((info.start_time, info.end_time), (10, 30));
+
+        let error = validated(&fixture, "b.csv", b"10,speed,1.5\nlater,speed,2\n").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn binary_formats_are_refused_until_they_can_be_read() {
+        let fixture = Fixture::new("binary");
+        let uploads = Uploads::open(&fixture.data_root).unwrap();
+        for name in ["a.arrow", "a.arrows", "a.parquet", "a.mcap"] {
+            let Err(status) = uploads.begin(name).await else {
+                panic!("{:?} was accepted", name);
+            };
+            assert_eq!(status.code(), tonic::Code::Unimplemented, "{:?}", name);
+
+            let error = validated(&fixture, name, b"\x89MCAP0\r\nPAR1ARROW1").unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::Unsupported, "{:?}", name);
+        }
+        assert!(!fixture.uploads_dir().exists() || fixture.entries().is_empty());
+
+        // Nor indexed if one was stored before
+        let stored = fixture.uploads_dir().join("1");
+        std::fs::create_dir_all(&stored).unwrap();
+        std::fs::write(stored.join("a.mcap"), b"\x89MCAP0\r\n").unwrap();
+        assert!(Uploads::open(&fixture.data_root).unwrap().list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn file_names_are_checked() {
+        let fixture = Fixture::new("names");
+        let uploads = Uploads::open(&fixture.data_root).unwrap();
+        for name in ["", ".hidden.csv", "notes.txt", "dir/.."] {
+            let Err(status) = uploads.begin(name).await else {
+                panic!("{:?} was accepted", name);
+            };
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "{:?}", name);
+        }
+        // Only the name is kept
+        assert_eq!(uploads.begin("../../elsewhere/a.csv").await.unwrap().file_name, "a.csv");
+    }
+
+    #[tokio::test]
+    async fn finished_uploads_are_stored_and_indexed() {
+        let fixture = Fixture::new("finish");
+        let uploads = Uploads::open(&fixture.data_root).unwrap();
+        let mut upload = uploads.begin("run.csv").await.unwrap();
+        upload.write(b"time,key,value\n").await.unwrap();
+        upload.write(b"1,speed,2\n").await.unwrap();
+        let info = uploads.finish(upload).await.unwrap();
+
+        assert_eq!(info.path, format!("uploads/{}/run.csv", info.id));
+        assert_eq!(info.size_bytes, 25);
+        assert_eq!(info.samples, 1);
+        assert_eq!(fixture.entries(), std::slice::from_ref(&info.id));
+        assert_eq!(uploads.list(), std::slice::from_ref(&info));
+
+        // And found again after a restart
+        assert_eq!(Uploads::open(&fixture.data_root).unwrap().list(), [info]);
+    }
+
+    #[tokio::test]
+    async fn rejected_and_abandoned_uploads_leave_no_part_file() {
+        let fixture = Fixture::new("cleanup");
+        let uploads = Uploads::open(&fixture.data_root).unwrap();
+
+        let mut rejected = uploads.begin("bad.csv").await.unwrap();
+        rejected.write(b"not a csv file\n").await.unwrap();
+        let status = uploads.finish(rejected).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(fixture.entries().is_empty());
+
+        let mut abandoned = uploads.begin("run.csv").await.unwrap();
+        abandoned.write(b"1,speed,2\n").await.unwrap();
+        assert_eq!(fixture.entries().len(), 1);
+        drop(abandoned);
+        assert!(fixture.entries().is_empty());
+        assert!(uploads.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn concurrent_uploads_get_their_own_part_files() {
+        let fixture = Fixture::new("concurrent");
+        let uploads = Uploads::open(&fixture.data_root).unwrap();
+        let mut first = uploads.begin("a.csv").await.unwrap();
+        let mut second = uploads.begin("a.csv").await.unwrap();
+        assert_ne!(first.part_path, second.part_path);
+
+        first.write(b"1,a,1\n").await.unwrap();
+        second.write(b"2,b,2\n").await.unwrap();
+        let first = uploads.finish(first).await.unwrap();
+        let second = uploads.finish(second).await.unwrap();
+        assert_ne!(first.id, second.id);
+        assert_eq!((first.keys, second.keys), (vec!["a".to_string()], vec!["b".to_string()]));
+    }
+
+    #[test]
+    fn leftover_part_files_are_removed_on_open() {
+        let fixture = Fixture::new("leftover");
+        std::fs::create_dir_all(fixture.uploads_dir()).unwrap();
+        std::fs::write(fixture.uploads_dir().join(".1-0.part"), b"1,a,1\n").unwrap();
+        assert!(Uploads::open(&fixture.data_root).unwrap().list().is_empty());
+        assert!(fixture.entries().is_empty());
+    }
+}
@@ -82,5 +82,199 @@ export class CSVServiceClient {
     this.methodDescriptorRequestCSV);
   }
 
+  methodDescriptorExportRerun = new grpcWeb.MethodDescriptor(
+    '/cursed.CSVService/ExportRerun',
+    grpcWeb.MethodType.UNARY,
+    cursed_pb.ExportRerunRequest,
+    cursed_pb.ExportRerunResponse,
+    (request: cursed_pb.ExportRerunRequest) => {
+      return request.serializeBinary();
+    },
+    cursed_pb.ExportRerunResponse.deserializeBinary
+  );
+
+  exportRerun(
+    request: cursed_pb.ExportRerunRequest,
+    metadata?: grpcWeb.Metadata | null): Promise<cursed_pb.ExportRerunResponse>;
+
+  exportRerun(
+    request: cursed_pb.ExportRerunRequest,
+    metadata: grpcWeb.Metadata | null,
+    callback: (err: grpcWeb.RpcError,
+               response: cursed_pb.ExportRerunResponse) => void): grpcWeb.ClientReadableStream<cursed_pb.ExportRerunResponse>;
+
+  exportRerun(
+    request: cursed_pb.ExportRerunRequest,
+    metadata?: grpcWeb.Metadata | null,
+    callback?: (err: grpcWeb.RpcError,
+               response: cursed_pb.ExportRerunResponse) => void) {
+    if (callback !== undefined) {
+      return this.client_.rpcCall(
+        this.hostname_ +
+          '/cursed.CSVService/ExportRerun',
+        request,
+        metadata || {},
+        this.methodDescriptorExportRerun,
+        callback);
+    }
+    return this.client_.unaryCall(
+    this.hostname_ +
+      '/cursed.CSVService/ExportRerun',
+    request,
+    metadata || {},
+    this.methodDescriptorExportRerun);
+  }
+
+  methodDescriptorListFiles = new grpcWeb.MethodDescriptor(
+    '/cursed.CSVService/ListFiles',
+    grpcWeb.MethodType.UNARY,
+    cursed_pb.ListFilesRequest,
+    cursed_pb.ListFilesResponse,
+    (request: cursed_pb.ListFilesRequest) => {
+      return request.serializeBinary();
+    },
+    cursed_pb.ListFilesResponse.deserializeBinary
+  );
+
+  listFiles(
+    request: cursed_pb.ListFilesRequest,
+    metadata?: grpcWeb.Metadata | null): Promise<cursed_pb.ListFilesResponse>;
+
+  listFiles(
+    request: cursed_pb.ListFilesRequest,
+    metadata: grpcWeb.Metadata | null,
+    callback: (err: grpcWeb.RpcError,
+               response: cursed_pb.ListFilesResponse) => void): grpcWeb.ClientReadableStream<cursed_pb.ListFilesResponse>;
+
+  listFiles(
+    request: cursed_pb.ListFilesRequest,
+    metadata?: grpcWeb.Metadata | null,
+    callback?: (err: grpcWeb.RpcError,
+               response: cursed_pb.ListFilesResponse) => void) {
+    if (callback !== undefined) {
+      return this.client_.rpcCall(
+        this.hostname_ +
+          '/cursed.CSVService/ListFiles',
+        request,
+        metadata || {},
+        this.methodDescriptorListFiles,
+        callback);
+    }
+    return this.client_.unaryCall(
+    this.hostname_ +
+      '/cursed.CSVService/ListFiles',
+    request,
+    metadata || {},
+    this.methodDescriptorListFiles);
+  }
+
+  methodDescriptorSubscribe = new grpcWeb.MethodDescriptor(
+    '/cursed.CSVService/Subscribe',
+    grpcWeb.MethodType.SERVER_STREAMING,
+    cursed_pb.SubscribeRequest,
+    cursed_pb.DataPointMsg,
+    (request: cursed_pb.SubscribeRequest) => {
+      return request.serializeBinary();
+    },
+    cursed_pb.DataPointMsg.deserializeBinary
+  );
+
+  subscribe(
+    request: cursed_pb.SubscribeRequest,
+    metadata?: grpcWeb.Metadata): grpcWeb.ClientReadableStream<cursed_pb.DataPointMsg> {
+    return this.client_.serverStreaming(
+      this.hostname_ +
+        '/cursed.CSVService/Subscribe',
+      request,
+      metadata || {},
+      this.methodDescriptorSubscribe);
+  }
+
+  methodDescriptorListDatasets = new grpcWeb.MethodDescriptor(
+    '/cursed.CSVService/ListDatasets',
+    grpcWeb.MethodType.UNARY,
+    cursed_pb.ListDatasetsRequest,
+    cursed_pb.ListDatasetsResponse,
+    (request: cursed_pb.ListDatasetsRequest) => {
+      return request.serializeBinary();
+    },
+    cursed_pb.ListDatasetsResponse.deserializeBinary
+  );
+
+  listDatasets(
+    request: cursed_pb.ListDatasetsRequest,
+    metadata?: grpcWeb.Metadata | null): Promise<cursed_pb.ListDatasetsResponse>;
+
+  listDatasets(
+    request: cursed_pb.ListDatasetsRequest,
+    metadata: grpcWeb.Metadata | null,
+    callback: (err: grpcWeb.RpcError,
+               response: cursed_pb.ListDatasetsResponse) => void): grpcWeb.ClientReadableStream<cursed_pb.ListDatasetsResponse>;
+
+  listDatasets(
+    request: cursed_pb.ListDatasetsRequest,
+    metadata?: grpcWeb.Metadata | null,
+    callback?: (err: grpcWeb.RpcError,
+               response: cursed_pb.ListDatasetsResponse) => void) {
+    if (callback !== undefined) {
+      return this.client_.rpcCall(
+        this.hostname_ +
+          '/cursed.CSVService/ListDatasets',
+        request,
+        metadata || {},
+        this.methodDescriptorListDatasets,
+        callback);
+    }
+    return this.client_.unaryCall(
+    this.hostname_ +
+      '/cursed.CSVService/ListDatasets',
+    request,
+    metadata || {},
+    this.methodDescriptorListDatasets);
+  }
+
+  methodDescriptorQueryRange = new grpcWeb.MethodDescriptor(
+    '/cursed.CSVService/QueryRange',
+    grpcWeb.MethodType.UNARY,
+    cursed_pb.QueryRangeRequest,
+    cursed_pb.QueryRangeResponse,
+    (request: cursed_pb.QueryRangeRequest) => {
+      return request.serializeBinary();
+    },
+    cursed_pb.QueryRangeResponse.deserializeBinary
+  );
+
+  queryRange(
+    request: cursed_pb.QueryRangeRequest,
+    metadata?: grpcWeb.Metadata | null): Promise<cursed_pb.QueryRangeResponse>;
+
+  queryRange(
+    request: cursed_pb.QueryRangeRequest,
+    metadata: grpcWeb.Metadata | null,
+    callback: (err: grpcWeb.RpcError,
+               response: cursed_pb.QueryRangeResponse) => void): grpcWeb.ClientReadableStream<cursed_pb.QueryRangeResponse>;
+
+  queryRange(
+    request: cursed_pb.QueryRangeRequest,
+    metadata?: grpcWeb.Metadata | null,
+    callback?: (err: grpcWeb.RpcError,
+               response: cursed_pb.QueryRangeResponse) => void) {
+    if (callback !== undefined) {
+      return this.client_.rpcCall(
+        this.hostname_ +
+          '/cursed.CSVService/QueryRange',
+        request,
+        metadata || {},
+        this.methodDescriptorQueryRange,
+        callback);
+    }
+    return this.client_.unaryCall(
+    this.hostname_ +
+      '/cursed.CSVService/QueryRange',
+    request,
+    metadata || {},
+    this.methodDescriptorQueryRange);
+  }
+
 }
 
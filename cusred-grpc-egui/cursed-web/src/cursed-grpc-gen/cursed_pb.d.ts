@@ -24,6 +24,9 @@ export class CSVResponse extends jspb.Message {
   getCsvContents(): string;
   setCsvContents(value: string): CSVResponse;
 
+  getWasSuccessfull(): boolean;
+  setWasSuccessfull(value: boolean): CSVResponse;
+
   serializeBinary(): Uint8Array;
   toObject(includeInstance?: boolean): CSVResponse.AsObject;
   static toObject(includeInstance: boolean, msg: CSVResponse): CSVResponse.AsObject;
@@ -35,6 +38,551 @@ export class CSVResponse extends jspb.Message {
 export namespace CSVResponse {
   export type AsObject = {
     csvContents: string,
+    wasSuccessfull: boolean,
+  }
+}
+
+export class ExportRerunRequest extends jspb.Message {
+  getPath(): string;
+  setPath(value: string): ExportRerunRequest;
+
+  getRrdPath(): string;
+  setRrdPath(value: string): ExportRerunRequest;
+
+  getViewerAddr(): string;
+  setViewerAddr(value: string): ExportRerunRequest;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): ExportRerunRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: ExportRerunRequest): ExportRerunRequest.AsObject;
+  static serializeBinaryToWriter(message: ExportRerunRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): ExportRerunRequest;
+  static deserializeBinaryFromReader(message: ExportRerunRequest, reader: jspb.BinaryReader): ExportRerunRequest;
+}
+
+export namespace ExportRerunRequest {
+  export type AsObject = {
+    path: string,
+    rrdPath: string,
+    viewerAddr: string,
+  }
+}
+
+export class ExportRerunResponse extends jspb.Message {
+  getWasSuccessfull(): boolean;
+  setWasSuccessfull(value: boolean): ExportRerunResponse;
+
+  getSamples(): number;
+  setSamples(value: number): ExportRerunResponse;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): ExportRerunResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: ExportRerunResponse): ExportRerunResponse.AsObject;
+  static serializeBinaryToWriter(message: ExportRerunResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): ExportRerunResponse;
+  static deserializeBinaryFromReader(message: ExportRerunResponse, reader: jspb.BinaryReader): ExportRerunResponse;
+}
+
+export namespace ExportRerunResponse {
+  export type AsObject = {
+    wasSuccessfull: boolean,
+    samples: number,
+  }
+}
+
+export class ListFilesRequest extends jspb.Message {
+  getPath(): string;
+  setPath(value: string): ListFilesRequest;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): ListFilesRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: ListFilesRequest): ListFilesRequest.AsObject;
+  static serializeBinaryToWriter(message: ListFilesRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): ListFilesRequest;
+  static deserializeBinaryFromReader(message: ListFilesRequest, reader: jspb.BinaryReader): ListFilesRequest;
+}
+
+export namespace ListFilesRequest {
+  export type AsObject = {
+    path: string,
+  }
+}
+
+export class FileEntry extends jspb.Message {
+  getPath(): string;
+  setPath(value: string): FileEntry;
+
+  getIsDir(): boolean;
+  setIsDir(value: boolean): FileEntry;
+
+  getSizeBytes(): number;
+  setSizeBytes(value: number): FileEntry;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): FileEntry.AsObject;
+  static toObject(includeInstance: boolean, msg: FileEntry): FileEntry.AsObject;
+  static serializeBinaryToWriter(message: FileEntry, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): FileEntry;
+  static deserializeBinaryFromReader(message: FileEntry, reader: jspb.BinaryReader): FileEntry;
+}
+
+export namespace FileEntry {
+  export type AsObject = {
+    path: string,
+    isDir: boolean,
+    sizeBytes: number,
+  }
+}
+
+export class ListFilesResponse extends jspb.Message {
+  getFilesList(): Array<FileEntry>;
+  setFilesList(value: Array<FileEntry>): ListFilesResponse;
+  clearFilesList(): ListFilesResponse;
+  addFiles(value?: FileEntry, index?: number): FileEntry;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): ListFilesResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: ListFilesResponse): ListFilesResponse.AsObject;
+  static serializeBinaryToWriter(message: ListFilesResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): ListFilesResponse;
+  static deserializeBinaryFromReader(message: ListFilesResponse, reader: jspb.BinaryReader): ListFilesResponse;
+}
+
+export namespace ListFilesResponse {
+  export type AsObject = {
+    filesList: Array<FileEntry.AsObject>,
+  }
+}
+
+export class SubscribeRequest extends jspb.Message {
+  getTopicsList(): Array<string>;
+  setTopicsList(value: Array<string>): SubscribeRequest;
+  clearTopicsList(): SubscribeRequest;
+  addTopics(value: string, index?: number): SubscribeRequest;
+
+  getStartTime(): number;
+  setStartTime(value: number): SubscribeRequest;
+  hasStartTime(): boolean;
+  clearStartTime(): SubscribeRequest;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SubscribeRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: SubscribeRequest): SubscribeRequest.AsObject;
+  static serializeBinaryToWriter(message: SubscribeRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SubscribeRequest;
+  static deserializeBinaryFromReader(message: SubscribeRequest, reader: jspb.BinaryReader): SubscribeRequest;
+}
+
+export namespace SubscribeRequest {
+  export type AsObject = {
+    topicsList: Array<string>,
+    startTime?: number,
+  }
+}
+
+export class Vec3 extends jspb.Message {
+  getX(): number;
+  setX(value: number): Vec3;
+
+  getY(): number;
+  setY(value: number): Vec3;
+
+  getZ(): number;
+  setZ(value: number): Vec3;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): Vec3.AsObject;
+  static toObject(includeInstance: boolean, msg: Vec3): Vec3.AsObject;
+  static serializeBinaryToWriter(message: Vec3, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): Vec3;
+  static deserializeBinaryFromReader(message: Vec3, reader: jspb.BinaryReader): Vec3;
+}
+
+export namespace Vec3 {
+  export type AsObject = {
+    x: number,
+    y: number,
+    z: number,
+  }
+}
+
+export class Pose extends jspb.Message {
+  getPosition(): Vec3 | undefined;
+  setPosition(value?: Vec3): Pose;
+  hasPosition(): boolean;
+  clearPosition(): Pose;
+
+  getRotation(): Vec3 | undefined;
+  setRotation(value?: Vec3): Pose;
+  hasRotation(): boolean;
+  clearRotation(): Pose;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): Pose.AsObject;
+  static toObject(includeInstance: boolean, msg: Pose): Pose.AsObject;
+  static serializeBinaryToWriter(message: Pose, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): Pose;
+  static deserializeBinaryFromReader(message: Pose, reader: jspb.BinaryReader): Pose;
+}
+
+export namespace Pose {
+  export type AsObject = {
+    position?: Vec3.AsObject,
+    rotation?: Vec3.AsObject,
+  }
+}
+
+export class TestData extends jspb.Message {
+  getPose(): Pose | undefined;
+  setPose(value?: Pose): TestData;
+  hasPose(): boolean;
+  clearPose(): TestData;
+
+  getVelocity(): Vec3 | undefined;
+  setVelocity(value?: Vec3): TestData;
+  hasVelocity(): boolean;
+  clearVelocity(): TestData;
+
+  getAcceleration(): Vec3 | undefined;
+  setAcceleration(value?: Vec3): TestData;
+  hasAcceleration(): boolean;
+  clearAcceleration(): TestData;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): TestData.AsObject;
+  static toObject(includeInstance: boolean, msg: TestData): TestData.AsObject;
+  static serializeBinaryToWriter(message: TestData, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): TestData;
+  static deserializeBinaryFromReader(message: TestData, reader: jspb.BinaryReader): TestData;
+}
+
+export namespace TestData {
+  export type AsObject = {
+    pose?: Pose.AsObject,
+    velocity?: Vec3.AsObject,
+    acceleration?: Vec3.AsObject,
+  }
+}
+
+export class DataPointMsg extends jspb.Message {
+  getTopic(): string;
+  setTopic(value: string): DataPointMsg;
+
+  getTime(): number;
+  setTime(value: number): DataPointMsg;
+
+  getScalar(): number;
+  setScalar(value: number): DataPointMsg;
+  hasScalar(): boolean;
+  clearScalar(): DataPointMsg;
+
+  getText(): string;
+  setText(value: string): DataPointMsg;
+  hasText(): boolean;
+  clearText(): DataPointMsg;
+
+  getVec3(): Vec3 | undefined;
+  setVec3(value?: Vec3): DataPointMsg;
+  hasVec3(): boolean;
+  clearVec3(): DataPointMsg;
+
+  getPose(): Pose | undefined;
+  setPose(value?: Pose): DataPointMsg;
+  hasPose(): boolean;
+  clearPose(): DataPointMsg;
+
+  getBytes(): Uint8Array | string;
+  getBytes_asU8(): Uint8Array;
+  getBytes_asB64(): string;
+  setBytes(value: Uint8Array | string): DataPointMsg;
+  hasBytes(): boolean;
+  clearBytes(): DataPointMsg;
+
+  getTestData(): TestData | undefined;
+  setTestData(value?: TestData): DataPointMsg;
+  hasTestData(): boolean;
+  clearTestData(): DataPointMsg;
+
+  getValueCase(): DataPointMsg.ValueCase;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): DataPointMsg.AsObject;
+  static toObject(includeInstance: boolean, msg: DataPointMsg): DataPointMsg.AsObject;
+  static serializeBinaryToWriter(message: DataPointMsg, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): DataPointMsg;
+  static deserializeBinaryFromReader(message: DataPointMsg, reader: jspb.BinaryReader): DataPointMsg;
+}
+
+export namespace DataPointMsg {
+  export type AsObject = {
+    topic: string,
+    time: number,
+    scalar: number,
+    text: string,
+    vec3?: Vec3.AsObject,
+    pose?: Pose.AsObject,
+    bytes: Uint8Array | string,
+    testData?: TestData.AsObject,
+  }
+
+  export enum ValueCase { 
+    VALUE_NOT_SET = 0,
+    SCALAR = 3,
+    TEXT = 4,
+    VEC3 = 5,
+    POSE = 6,
+    BYTES = 7,
+    TEST_DATA = 8,
+  }
+}
+
+export class DataPointBatch extends jspb.Message {
+  getDatapointsList(): Array<DataPointMsg>;
+  setDatapointsList(value: Array<DataPointMsg>): DataPointBatch;
+  clearDatapointsList(): DataPointBatch;
+  addDatapoints(value?: DataPointMsg, index?: number): DataPointMsg;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): DataPointBatch.AsObject;
+  static toObject(includeInstance: boolean, msg: DataPointBatch): DataPointBatch.AsObject;
+  static serializeBinaryToWriter(message: DataPointBatch, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): DataPointBatch;
+  static deserializeBinaryFromReader(message: DataPointBatch, reader: jspb.BinaryReader): DataPointBatch;
+}
+
+export namespace DataPointBatch {
+  export type AsObject = {
+    datapointsList: Array<DataPointMsg.AsObject>,
   }
 }
 
+export class UploadChunk extends jspb.Message {
+  getHeader(): UploadHeader | undefined;
+  setHeader(value?: UploadHeader): UploadChunk;
+  hasHeader(): boolean;
+  clearHeader(): UploadChunk;
+
+  getData(): Uint8Array | string;
+  getData_asU8(): Uint8Array;
+  getData_asB64(): string;
+  setData(value: Uint8Array | string): UploadChunk;
+  hasData(): boolean;
+  clearData(): UploadChunk;
+
+  getChunkCase(): UploadChunk.ChunkCase;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): UploadChunk.AsObject;
+  static toObject(includeInstance: boolean, msg: UploadChunk): UploadChunk.AsObject;
+  static serializeBinaryToWriter(message: UploadChunk, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): UploadChunk;
+  static deserializeBinaryFromReader(message: UploadChunk, reader: jspb.BinaryReader): UploadChunk;
+}
+
+export namespace UploadChunk {
+  export type AsObject = {
+    header?: UploadHeader.AsObject,
+    data: Uint8Array | string,
+  }
+
+  export enum ChunkCase { 
+    CHUNK_NOT_SET = 0,
+    HEADER = 1,
+    DATA = 2,
+  }
+}
+
+export class UploadHeader extends jspb.Message {
+  getFileName(): string;
+  setFileName(value: string): UploadHeader;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): UploadHeader.AsObject;
+  static toObject(includeInstance: boolean, msg: UploadHeader): UploadHeader.AsObject;
+  static serializeBinaryToWriter(message: UploadHeader, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): UploadHeader;
+  static deserializeBinaryFromReader(message: UploadHeader, reader: jspb.BinaryReader): UploadHeader;
+}
+
+export namespace UploadHeader {
+  export type AsObject = {
+    fileName: string,
+  }
+}
+
+export class DatasetInfo extends jspb.Message {
+  getId(): string;
+  setId(value: string): DatasetInfo;
+
+  getPath(): string;
+  setPath(value: string): DatasetInfo;
+
+  getFormat(): DatasetFormat;
+  setFormat(value: DatasetFormat): DatasetInfo;
+
+  getSizeBytes(): number;
+  setSizeBytes(value: number): DatasetInfo;
+
+  getSamples(): number;
+  setSamples(value: number): DatasetInfo;
+
+  getKeysList(): Array<string>;
+  setKeysList(value: Array<string>): DatasetInfo;
+  clearKeysList(): DatasetInfo;
+  addKeys(value: string, index?: number): DatasetInfo;
+
+  getStartTime(): number;
+  setStartTime(value: number): DatasetInfo;
+
+  getEndTime(): number;
+  setEndTime(value: number): DatasetInfo;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): DatasetInfo.AsObject;
+  static toObject(includeInstance: boolean, msg: DatasetInfo): DatasetInfo.AsObject;
+  static serializeBinaryToWriter(message: DatasetInfo, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): DatasetInfo;
+  static deserializeBinaryFromReader(message: DatasetInfo, reader: jspb.BinaryReader): DatasetInfo;
+}
+
+export namespace DatasetInfo {
+  export type AsObject = {
+    id: string,
+    path: string,
+    format: DatasetFormat,
+    sizeBytes: number,
+    samples: number,
+    keysList: Array<string>,
+    startTime: number,
+    endTime: number,
+  }
+}
+
+export class UploadResponse extends jspb.Message {
+  getDataset(): DatasetInfo | undefined;
+  setDataset(value?: DatasetInfo): UploadResponse;
+  hasDataset(): boolean;
+  clearDataset(): UploadResponse;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): UploadResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: UploadResponse): UploadResponse.AsObject;
+  static serializeBinaryToWriter(message: UploadResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): UploadResponse;
+  static deserializeBinaryFromReader(message: UploadResponse, reader: jspb.BinaryReader): UploadResponse;
+}
+
+export namespace UploadResponse {
+  export type AsObject = {
+    dataset?: DatasetInfo.AsObject,
+  }
+}
+
+export class ListDatasetsRequest extends jspb.Message {
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): ListDatasetsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: ListDatasetsRequest): ListDatasetsRequest.AsObject;
+  static serializeBinaryToWriter(message: ListDatasetsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): ListDatasetsRequest;
+  static deserializeBinaryFromReader(message: ListDatasetsRequest, reader: jspb.BinaryReader): ListDatasetsRequest;
+}
+
+export namespace ListDatasetsRequest {
+  export type AsObject = {
+  }
+}
+
+export class ListDatasetsResponse extends jspb.Message {
+  getDatasetsList(): Array<DatasetInfo>;
+  setDatasetsList(value: Array<DatasetInfo>): ListDatasetsResponse;
+  clearDatasetsList(): ListDatasetsResponse;
+  addDatasets(value?: DatasetInfo, index?: number): DatasetInfo;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): ListDatasetsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: ListDatasetsResponse): ListDatasetsResponse.AsObject;
+  static serializeBinaryToWriter(message: ListDatasetsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): ListDatasetsResponse;
+  static deserializeBinaryFromReader(message: ListDatasetsResponse, reader: jspb.BinaryReader): ListDatasetsResponse;
+}
+
+export namespace ListDatasetsResponse {
+  export type AsObject = {
+    datasetsList: Array<DatasetInfo.AsObject>,
+  }
+}
+
+export class QueryRangeRequest extends jspb.Message {
+  getPath(): string;
+  setPath(value: string): QueryRangeRequest;
+
+  getTopic(): string;
+  setTopic(value: string): QueryRangeRequest;
+
+  getTStart(): number;
+  setTStart(value: number): QueryRangeRequest;
+
+  getTEnd(): number;
+  setTEnd(value: number): QueryRangeRequest;
+
+  getMaxPoints(): number;
+  setMaxPoints(value: number): QueryRangeRequest;
+
+  getDownsampling(): Downsampling;
+  setDownsampling(value: Downsampling): QueryRangeRequest;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): QueryRangeRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: QueryRangeRequest): QueryRangeRequest.AsObject;
+  static serializeBinaryToWriter(message: QueryRangeRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): QueryRangeRequest;
+  static deserializeBinaryFromReader(message: QueryRangeRequest, reader: jspb.BinaryReader): QueryRangeRequest;
+}
+
+export namespace QueryRangeRequest {
+  export type AsObject = {
+    path: string,
+    topic: string,
+    tStart: number,
+    tEnd: number,
+    maxPoints: number,
+    downsampling: Downsampling,
+  }
+}
+
+export class QueryRangeResponse extends jspb.Message {
+  getPointsList(): Array<DataPointMsg>;
+  setPointsList(value: Array<DataPointMsg>): QueryRangeResponse;
+  clearPointsList(): QueryRangeResponse;
+  addPoints(value?: DataPointMsg, index?: number): DataPointMsg;
+
+  getTotalPoints(): number;
+  setTotalPoints(value: number): QueryRangeResponse;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): QueryRangeResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: QueryRangeResponse): QueryRangeResponse.AsObject;
+  static serializeBinaryToWriter(message: QueryRangeResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): QueryRangeResponse;
+  static deserializeBinaryFromReader(message: QueryRangeResponse, reader: jspb.BinaryReader): QueryRangeResponse;
+}
+
+export namespace QueryRangeResponse {
+  export type AsObject = {
+    pointsList: Array<DataPointMsg.AsObject>,
+    totalPoints: number,
+  }
+}
+
+export enum DatasetFormat { 
+  DATASET_FORMAT_UNKNOWN = 0,
+  DATASET_FORMAT_CSV = 1,
+  DATASET_FORMAT_ARROW_FILE = 2,
+  DATASET_FORMAT_ARROW_STREAM = 3,
+  DATASET_FORMAT_PARQUET = 4,
+  DATASET_FORMAT_MCAP = 5,
+}
+export enum Downsampling { 
+  DOWNSAMPLING_MIN_MAX = 0,
+  DOWNSAMPLING_LTTB = 1,
+}
@@ -23,6 +23,29 @@ var global =
 
 goog.exportSymbol('proto.cursed.CSVRequest', null, global);
 goog.exportSymbol('proto.cursed.CSVResponse', null, global);
+goog.exportSymbol('proto.cursed.DataPointBatch', null, global);
+goog.exportSymbol('proto.cursed.DataPointMsg', null, global);
+goog.exportSymbol('proto.cursed.DataPointMsg.ValueCase', null, global);
+goog.exportSymbol('proto.cursed.DatasetFormat', null, global);
+goog.exportSymbol('proto.cursed.DatasetInfo', null, global);
+goog.exportSymbol('proto.cursed.Downsampling', null, global);
+goog.exportSymbol('proto.cursed.ExportRerunRequest', null, global);
+goog.exportSymbol('proto.cursed.ExportRerunResponse', null, global);
+goog.exportSymbol('proto.cursed.FileEntry', null, global);
+goog.exportSymbol('proto.cursed.ListDatasetsRequest', null, global);
+goog.exportSymbol('proto.cursed.ListDatasetsResponse', null, global);
+goog.exportSymbol('proto.cursed.ListFilesRequest', null, global);
+goog.exportSymbol('proto.cursed.ListFilesResponse', null, global);
+goog.exportSymbol('proto.cursed.Pose', null, global);
+goog.exportSymbol('proto.cursed.QueryRangeRequest', null, global);
+goog.exportSymbol('proto.cursed.QueryRangeResponse', null, global);
+goog.exportSymbol('proto.cursed.SubscribeRequest', null, global);
+goog.exportSymbol('proto.cursed.TestData', null, global);
+goog.exportSymbol('proto.cursed.UploadChunk', null, global);
+goog.exportSymbol('proto.cursed.UploadChunk.ChunkCase', null, global);
+goog.exportSymbol('proto.cursed.UploadHeader', null, global);
+goog.exportSymbol('proto.cursed.UploadResponse', null, global);
+goog.exportSymbol('proto.cursed.Vec3', null, global);
 /**
  * Generated by JsPbCodeGenerator.
  * @param {Array=} opt_data Optional initial data array, typically from a
@@ -65,6 +88,4202 @@ if (goog.DEBUG && !COMPILED) {
    */
   proto.cursed.CSVResponse.displayName = 'proto.cursed.CSVResponse';
 }
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.ExportRerunRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.ExportRerunRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.ExportRerunRequest.displayName = 'proto.cursed.ExportRerunRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.ExportRerunResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.ExportRerunResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.ExportRerunResponse.displayName = 'proto.cursed.ExportRerunResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.ListFilesRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.ListFilesRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.ListFilesRequest.displayName = 'proto.cursed.ListFilesRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.FileEntry = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.FileEntry, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.FileEntry.displayName = 'proto.cursed.FileEntry';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.ListFilesResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.cursed.ListFilesResponse.repeatedFields_, null);
+};
+goog.inherits(proto.cursed.ListFilesResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.ListFilesResponse.displayName = 'proto.cursed.ListFilesResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.SubscribeRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.cursed.SubscribeRequest.repeatedFields_, null);
+};
+goog.inherits(proto.cursed.SubscribeRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.SubscribeRequest.displayName = 'proto.cursed.SubscribeRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.Vec3 = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.Vec3, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.Vec3.displayName = 'proto.cursed.Vec3';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.Pose = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.Pose, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.Pose.displayName = 'proto.cursed.Pose';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.TestData = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.TestData, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.TestData.displayName = 'proto.cursed.TestData';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.DataPointMsg = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.cursed.DataPointMsg.oneofGroups_);
+};
+goog.inherits(proto.cursed.DataPointMsg, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.DataPointMsg.displayName = 'proto.cursed.DataPointMsg';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.DataPointBatch = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.cursed.DataPointBatch.repeatedFields_, null);
+};
+goog.inherits(proto.cursed.DataPointBatch, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.DataPointBatch.displayName = 'proto.cursed.DataPointBatch';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.UploadChunk = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.cursed.UploadChunk.oneofGroups_);
+};
+goog.inherits(proto.cursed.UploadChunk, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.UploadChunk.displayName = 'proto.cursed.UploadChunk';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.UploadHeader = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.UploadHeader, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.UploadHeader.displayName = 'proto.cursed.UploadHeader';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.DatasetInfo = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.cursed.DatasetInfo.repeatedFields_, null);
+};
+goog.inherits(proto.cursed.DatasetInfo, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.DatasetInfo.displayName = 'proto.cursed.DatasetInfo';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.UploadResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.UploadResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.UploadResponse.displayName = 'proto.cursed.UploadResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.ListDatasetsRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.ListDatasetsRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.ListDatasetsRequest.displayName = 'proto.cursed.ListDatasetsRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.ListDatasetsResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.cursed.ListDatasetsResponse.repeatedFields_, null);
+};
+goog.inherits(proto.cursed.ListDatasetsResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.ListDatasetsResponse.displayName = 'proto.cursed.ListDatasetsResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.QueryRangeRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.cursed.QueryRangeRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.QueryRangeRequest.displayName = 'proto.cursed.QueryRangeRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.cursed.QueryRangeResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.cursed.QueryRangeResponse.repeatedFields_, null);
+};
+goog.inherits(proto.cursed.QueryRangeResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.cursed.QueryRangeResponse.displayName = 'proto.cursed.QueryRangeResponse';
+}
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.CSVRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.CSVRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.CSVRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.CSVRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    path: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.CSVRequest}
+ */
+proto.cursed.CSVRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.CSVRequest;
+  return proto.cursed.CSVRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.CSVRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.CSVRequest}
+ */
+proto.cursed.CSVRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setPath(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.CSVRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.CSVRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.CSVRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.CSVRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPath();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string path = 1;
+ * @return {string}
+ */
+proto.cursed.CSVRequest.prototype.getPath = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.CSVRequest} returns this
+ */
+proto.cursed.CSVRequest.prototype.setPath = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.CSVResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.CSVResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.CSVResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.CSVResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    csvContents: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    wasSuccessfull: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.CSVResponse}
+ */
+proto.cursed.CSVResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.CSVResponse;
+  return proto.cursed.CSVResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.CSVResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.CSVResponse}
+ */
+proto.cursed.CSVResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setCsvContents(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setWasSuccessfull(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.CSVResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.CSVResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.CSVResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.CSVResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getCsvContents();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getWasSuccessfull();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string csv_contents = 1;
+ * @return {string}
+ */
+proto.cursed.CSVResponse.prototype.getCsvContents = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.CSVResponse} returns this
+ */
+proto.cursed.CSVResponse.prototype.setCsvContents = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional bool was_successfull = 2;
+ * @return {boolean}
+ */
+proto.cursed.CSVResponse.prototype.getWasSuccessfull = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.cursed.CSVResponse} returns this
+ */
+proto.cursed.CSVResponse.prototype.setWasSuccessfull = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.ExportRerunRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.ExportRerunRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.ExportRerunRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ExportRerunRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    path: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    rrdPath: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    viewerAddr: jspb.Message.getFieldWithDefault(msg, 3, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.ExportRerunRequest}
+ */
+proto.cursed.ExportRerunRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.ExportRerunRequest;
+  return proto.cursed.ExportRerunRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.ExportRerunRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.ExportRerunRequest}
+ */
+proto.cursed.ExportRerunRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setPath(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setRrdPath(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setViewerAddr(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.ExportRerunRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.ExportRerunRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.ExportRerunRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ExportRerunRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPath();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getRrdPath();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getViewerAddr();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string path = 1;
+ * @return {string}
+ */
+proto.cursed.ExportRerunRequest.prototype.getPath = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.ExportRerunRequest} returns this
+ */
+proto.cursed.ExportRerunRequest.prototype.setPath = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string rrd_path = 2;
+ * @return {string}
+ */
+proto.cursed.ExportRerunRequest.prototype.getRrdPath = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.ExportRerunRequest} returns this
+ */
+proto.cursed.ExportRerunRequest.prototype.setRrdPath = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional string viewer_addr = 3;
+ * @return {string}
+ */
+proto.cursed.ExportRerunRequest.prototype.getViewerAddr = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.ExportRerunRequest} returns this
+ */
+proto.cursed.ExportRerunRequest.prototype.setViewerAddr = function(value) {
+  return jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.ExportRerunResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.ExportRerunResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.ExportRerunResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ExportRerunResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    wasSuccessfull: jspb.Message.getBooleanFieldWithDefault(msg, 1, false),
+    samples: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.ExportRerunResponse}
+ */
+proto.cursed.ExportRerunResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.ExportRerunResponse;
+  return proto.cursed.ExportRerunResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.ExportRerunResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.ExportRerunResponse}
+ */
+proto.cursed.ExportRerunResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setWasSuccessfull(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setSamples(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.ExportRerunResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.ExportRerunResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.ExportRerunResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ExportRerunResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getWasSuccessfull();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getSamples();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional bool was_successfull = 1;
+ * @return {boolean}
+ */
+proto.cursed.ExportRerunResponse.prototype.getWasSuccessfull = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 1, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.cursed.ExportRerunResponse} returns this
+ */
+proto.cursed.ExportRerunResponse.prototype.setWasSuccessfull = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional uint64 samples = 2;
+ * @return {number}
+ */
+proto.cursed.ExportRerunResponse.prototype.getSamples = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.ExportRerunResponse} returns this
+ */
+proto.cursed.ExportRerunResponse.prototype.setSamples = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.ListFilesRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.ListFilesRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.ListFilesRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListFilesRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    path: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.ListFilesRequest}
+ */
+proto.cursed.ListFilesRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.ListFilesRequest;
+  return proto.cursed.ListFilesRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.ListFilesRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.ListFilesRequest}
+ */
+proto.cursed.ListFilesRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setPath(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.ListFilesRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.ListFilesRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.ListFilesRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListFilesRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPath();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string path = 1;
+ * @return {string}
+ */
+proto.cursed.ListFilesRequest.prototype.getPath = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.ListFilesRequest} returns this
+ */
+proto.cursed.ListFilesRequest.prototype.setPath = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.FileEntry.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.FileEntry.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.FileEntry} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.FileEntry.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    path: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    isDir: jspb.Message.getBooleanFieldWithDefault(msg, 2, false),
+    sizeBytes: jspb.Message.getFieldWithDefault(msg, 3, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.FileEntry}
+ */
+proto.cursed.FileEntry.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.FileEntry;
+  return proto.cursed.FileEntry.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.FileEntry} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.FileEntry}
+ */
+proto.cursed.FileEntry.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setPath(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setIsDir(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setSizeBytes(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.FileEntry.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.FileEntry.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.FileEntry} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.FileEntry.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPath();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getIsDir();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
+    );
+  }
+  f = message.getSizeBytes();
+  if (f !== 0) {
+    writer.writeUint64(
+      3,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string path = 1;
+ * @return {string}
+ */
+proto.cursed.FileEntry.prototype.getPath = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.FileEntry} returns this
+ */
+proto.cursed.FileEntry.prototype.setPath = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional bool is_dir = 2;
+ * @return {boolean}
+ */
+proto.cursed.FileEntry.prototype.getIsDir = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.cursed.FileEntry} returns this
+ */
+proto.cursed.FileEntry.prototype.setIsDir = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
+};
+
+
+/**
+ * optional uint64 size_bytes = 3;
+ * @return {number}
+ */
+proto.cursed.FileEntry.prototype.getSizeBytes = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.FileEntry} returns this
+ */
+proto.cursed.FileEntry.prototype.setSizeBytes = function(value) {
+  return jspb.Message.setProto3IntField(this, 3, value);
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.cursed.ListFilesResponse.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.ListFilesResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.ListFilesResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.ListFilesResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListFilesResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    filesList: jspb.Message.toObjectList(msg.getFilesList(),
+    proto.cursed.FileEntry.toObject, includeInstance)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.ListFilesResponse}
+ */
+proto.cursed.ListFilesResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.ListFilesResponse;
+  return proto.cursed.ListFilesResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.ListFilesResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.ListFilesResponse}
+ */
+proto.cursed.ListFilesResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.FileEntry;
+      reader.readMessage(value,proto.cursed.FileEntry.deserializeBinaryFromReader);
+      msg.addFiles(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.ListFilesResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.ListFilesResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.ListFilesResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListFilesResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getFilesList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.cursed.FileEntry.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated FileEntry files = 1;
+ * @return {!Array<!proto.cursed.FileEntry>}
+ */
+proto.cursed.ListFilesResponse.prototype.getFilesList = function() {
+  return /** @type{!Array<!proto.cursed.FileEntry>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.cursed.FileEntry, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.cursed.FileEntry>} value
+ * @return {!proto.cursed.ListFilesResponse} returns this
+*/
+proto.cursed.ListFilesResponse.prototype.setFilesList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.cursed.FileEntry=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.cursed.FileEntry}
+ */
+proto.cursed.ListFilesResponse.prototype.addFiles = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.cursed.FileEntry, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.cursed.ListFilesResponse} returns this
+ */
+proto.cursed.ListFilesResponse.prototype.clearFilesList = function() {
+  return this.setFilesList([]);
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.cursed.SubscribeRequest.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.SubscribeRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.SubscribeRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.SubscribeRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.SubscribeRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    topicsList: (f = jspb.Message.getRepeatedField(msg, 1)) == null ? undefined : f,
+    startTime: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.SubscribeRequest}
+ */
+proto.cursed.SubscribeRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.SubscribeRequest;
+  return proto.cursed.SubscribeRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.SubscribeRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.SubscribeRequest}
+ */
+proto.cursed.SubscribeRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.addTopics(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setStartTime(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.SubscribeRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.SubscribeRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.SubscribeRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.SubscribeRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getTopicsList();
+  if (f.length > 0) {
+    writer.writeRepeatedString(
+      1,
+      f
+    );
+  }
+  f = /** @type {number} */ (jspb.Message.getField(message, 2));
+  if (f != null) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * repeated string topics = 1;
+ * @return {!Array<string>}
+ */
+proto.cursed.SubscribeRequest.prototype.getTopicsList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
+};
+
+
+/**
+ * @param {!Array<string>} value
+ * @return {!proto.cursed.SubscribeRequest} returns this
+ */
+proto.cursed.SubscribeRequest.prototype.setTopicsList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
+};
+
+
+/**
+ * @param {string} value
+ * @param {number=} opt_index
+ * @return {!proto.cursed.SubscribeRequest} returns this
+ */
+proto.cursed.SubscribeRequest.prototype.addTopics = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.cursed.SubscribeRequest} returns this
+ */
+proto.cursed.SubscribeRequest.prototype.clearTopicsList = function() {
+  return this.setTopicsList([]);
+};
+
+
+/**
+ * optional uint64 start_time = 2;
+ * @return {number}
+ */
+proto.cursed.SubscribeRequest.prototype.getStartTime = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.SubscribeRequest} returns this
+ */
+proto.cursed.SubscribeRequest.prototype.setStartTime = function(value) {
+  return jspb.Message.setField(this, 2, value);
+};
+
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.cursed.SubscribeRequest} returns this
+ */
+proto.cursed.SubscribeRequest.prototype.clearStartTime = function() {
+  return jspb.Message.setField(this, 2, undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.SubscribeRequest.prototype.hasStartTime = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.Vec3.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.Vec3.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.Vec3} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.Vec3.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    x: jspb.Message.getFloatingPointFieldWithDefault(msg, 1, 0.0),
+    y: jspb.Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
+    z: jspb.Message.getFloatingPointFieldWithDefault(msg, 3, 0.0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.Vec3}
+ */
+proto.cursed.Vec3.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.Vec3;
+  return proto.cursed.Vec3.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.Vec3} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.Vec3}
+ */
+proto.cursed.Vec3.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readDouble());
+      msg.setX(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readDouble());
+      msg.setY(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readDouble());
+      msg.setZ(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.Vec3.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.Vec3.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.Vec3} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.Vec3.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getX();
+  if (f !== 0.0) {
+    writer.writeDouble(
+      1,
+      f
+    );
+  }
+  f = message.getY();
+  if (f !== 0.0) {
+    writer.writeDouble(
+      2,
+      f
+    );
+  }
+  f = message.getZ();
+  if (f !== 0.0) {
+    writer.writeDouble(
+      3,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional double x = 1;
+ * @return {number}
+ */
+proto.cursed.Vec3.prototype.getX = function() {
+  return /** @type {number} */ (jspb.Message.getFloatingPointFieldWithDefault(this, 1, 0.0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.Vec3} returns this
+ */
+proto.cursed.Vec3.prototype.setX = function(value) {
+  return jspb.Message.setProto3FloatField(this, 1, value);
+};
+
+
+/**
+ * optional double y = 2;
+ * @return {number}
+ */
+proto.cursed.Vec3.prototype.getY = function() {
+  return /** @type {number} */ (jspb.Message.getFloatingPointFieldWithDefault(this, 2, 0.0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.Vec3} returns this
+ */
+proto.cursed.Vec3.prototype.setY = function(value) {
+  return jspb.Message.setProto3FloatField(this, 2, value);
+};
+
+
+/**
+ * optional double z = 3;
+ * @return {number}
+ */
+proto.cursed.Vec3.prototype.getZ = function() {
+  return /** @type {number} */ (jspb.Message.getFloatingPointFieldWithDefault(this, 3, 0.0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.Vec3} returns this
+ */
+proto.cursed.Vec3.prototype.setZ = function(value) {
+  return jspb.Message.setProto3FloatField(this, 3, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.Pose.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.Pose.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.Pose} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.Pose.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    position: (f = msg.getPosition()) && proto.cursed.Vec3.toObject(includeInstance, f),
+    rotation: (f = msg.getRotation()) && proto.cursed.Vec3.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.Pose}
+ */
+proto.cursed.Pose.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.Pose;
+  return proto.cursed.Pose.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.Pose} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.Pose}
+ */
+proto.cursed.Pose.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.Vec3;
+      reader.readMessage(value,proto.cursed.Vec3.deserializeBinaryFromReader);
+      msg.setPosition(value);
+      break;
+    case 2:
+      var value = new proto.cursed.Vec3;
+      reader.readMessage(value,proto.cursed.Vec3.deserializeBinaryFromReader);
+      msg.setRotation(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.Pose.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.Pose.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.Pose} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.Pose.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPosition();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.cursed.Vec3.serializeBinaryToWriter
+    );
+  }
+  f = message.getRotation();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.cursed.Vec3.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional Vec3 position = 1;
+ * @return {?proto.cursed.Vec3}
+ */
+proto.cursed.Pose.prototype.getPosition = function() {
+  return /** @type{?proto.cursed.Vec3} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Vec3, 1));
+};
+
+
+/**
+ * @param {?proto.cursed.Vec3|undefined} value
+ * @return {!proto.cursed.Pose} returns this
+*/
+proto.cursed.Pose.prototype.setPosition = function(value) {
+  return jspb.Message.setWrapperField(this, 1, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.Pose} returns this
+ */
+proto.cursed.Pose.prototype.clearPosition = function() {
+  return this.setPosition(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.Pose.prototype.hasPosition = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Vec3 rotation = 2;
+ * @return {?proto.cursed.Vec3}
+ */
+proto.cursed.Pose.prototype.getRotation = function() {
+  return /** @type{?proto.cursed.Vec3} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Vec3, 2));
+};
+
+
+/**
+ * @param {?proto.cursed.Vec3|undefined} value
+ * @return {!proto.cursed.Pose} returns this
+*/
+proto.cursed.Pose.prototype.setRotation = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.Pose} returns this
+ */
+proto.cursed.Pose.prototype.clearRotation = function() {
+  return this.setRotation(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.Pose.prototype.hasRotation = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.TestData.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.TestData.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.TestData} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.TestData.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    pose: (f = msg.getPose()) && proto.cursed.Pose.toObject(includeInstance, f),
+    velocity: (f = msg.getVelocity()) && proto.cursed.Vec3.toObject(includeInstance, f),
+    acceleration: (f = msg.getAcceleration()) && proto.cursed.Vec3.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.TestData}
+ */
+proto.cursed.TestData.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.TestData;
+  return proto.cursed.TestData.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.TestData} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.TestData}
+ */
+proto.cursed.TestData.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.Pose;
+      reader.readMessage(value,proto.cursed.Pose.deserializeBinaryFromReader);
+      msg.setPose(value);
+      break;
+    case 2:
+      var value = new proto.cursed.Vec3;
+      reader.readMessage(value,proto.cursed.Vec3.deserializeBinaryFromReader);
+      msg.setVelocity(value);
+      break;
+    case 3:
+      var value = new proto.cursed.Vec3;
+      reader.readMessage(value,proto.cursed.Vec3.deserializeBinaryFromReader);
+      msg.setAcceleration(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.TestData.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.TestData.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.TestData} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.TestData.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPose();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.cursed.Pose.serializeBinaryToWriter
+    );
+  }
+  f = message.getVelocity();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.cursed.Vec3.serializeBinaryToWriter
+    );
+  }
+  f = message.getAcceleration();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.cursed.Vec3.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional Pose pose = 1;
+ * @return {?proto.cursed.Pose}
+ */
+proto.cursed.TestData.prototype.getPose = function() {
+  return /** @type{?proto.cursed.Pose} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Pose, 1));
+};
+
+
+/**
+ * @param {?proto.cursed.Pose|undefined} value
+ * @return {!proto.cursed.TestData} returns this
+*/
+proto.cursed.TestData.prototype.setPose = function(value) {
+  return jspb.Message.setWrapperField(this, 1, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.TestData} returns this
+ */
+proto.cursed.TestData.prototype.clearPose = function() {
+  return this.setPose(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.TestData.prototype.hasPose = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Vec3 velocity = 2;
+ * @return {?proto.cursed.Vec3}
+ */
+proto.cursed.TestData.prototype.getVelocity = function() {
+  return /** @type{?proto.cursed.Vec3} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Vec3, 2));
+};
+
+
+/**
+ * @param {?proto.cursed.Vec3|undefined} value
+ * @return {!proto.cursed.TestData} returns this
+*/
+proto.cursed.TestData.prototype.setVelocity = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.TestData} returns this
+ */
+proto.cursed.TestData.prototype.clearVelocity = function() {
+  return this.setVelocity(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.TestData.prototype.hasVelocity = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional Vec3 acceleration = 3;
+ * @return {?proto.cursed.Vec3}
+ */
+proto.cursed.TestData.prototype.getAcceleration = function() {
+  return /** @type{?proto.cursed.Vec3} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Vec3, 3));
+};
+
+
+/**
+ * @param {?proto.cursed.Vec3|undefined} value
+ * @return {!proto.cursed.TestData} returns this
+*/
+proto.cursed.TestData.prototype.setAcceleration = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.TestData} returns this
+ */
+proto.cursed.TestData.prototype.clearAcceleration = function() {
+  return this.setAcceleration(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.TestData.prototype.hasAcceleration = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.cursed.DataPointMsg.oneofGroups_ = [[3,4,5,6,7,8]];
+
+/**
+ * @enum {number}
+ */
+proto.cursed.DataPointMsg.ValueCase = {
+  VALUE_NOT_SET: 0,
+  SCALAR: 3,
+  TEXT: 4,
+  VEC3: 5,
+  POSE: 6,
+  BYTES: 7,
+  TEST_DATA: 8
+};
+
+/**
+ * @return {proto.cursed.DataPointMsg.ValueCase}
+ */
+proto.cursed.DataPointMsg.prototype.getValueCase = function() {
+  return /** @type {proto.cursed.DataPointMsg.ValueCase} */(jspb.Message.computeOneofCase(this, proto.cursed.DataPointMsg.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.DataPointMsg.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.DataPointMsg.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.DataPointMsg} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.DataPointMsg.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    topic: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    time: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    scalar: jspb.Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+    text: jspb.Message.getFieldWithDefault(msg, 4, ""),
+    vec3: (f = msg.getVec3()) && proto.cursed.Vec3.toObject(includeInstance, f),
+    pose: (f = msg.getPose()) && proto.cursed.Pose.toObject(includeInstance, f),
+    bytes: msg.getBytes_asB64(),
+    testData: (f = msg.getTestData()) && proto.cursed.TestData.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.DataPointMsg}
+ */
+proto.cursed.DataPointMsg.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.DataPointMsg;
+  return proto.cursed.DataPointMsg.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.DataPointMsg} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.DataPointMsg}
+ */
+proto.cursed.DataPointMsg.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTopic(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setTime(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readDouble());
+      msg.setScalar(value);
+      break;
+    case 4:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setText(value);
+      break;
+    case 5:
+      var value = new proto.cursed.Vec3;
+      reader.readMessage(value,proto.cursed.Vec3.deserializeBinaryFromReader);
+      msg.setVec3(value);
+      break;
+    case 6:
+      var value = new proto.cursed.Pose;
+      reader.readMessage(value,proto.cursed.Pose.deserializeBinaryFromReader);
+      msg.setPose(value);
+      break;
+    case 7:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setBytes(value);
+      break;
+    case 8:
+      var value = new proto.cursed.TestData;
+      reader.readMessage(value,proto.cursed.TestData.deserializeBinaryFromReader);
+      msg.setTestData(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.DataPointMsg.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.DataPointMsg.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.DataPointMsg} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.DataPointMsg.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getTopic();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getTime();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
+  f = /** @type {number} */ (jspb.Message.getField(message, 3));
+  if (f != null) {
+    writer.writeDouble(
+      3,
+      f
+    );
+  }
+  f = /** @type {string} */ (jspb.Message.getField(message, 4));
+  if (f != null) {
+    writer.writeString(
+      4,
+      f
+    );
+  }
+  f = message.getVec3();
+  if (f != null) {
+    writer.writeMessage(
+      5,
+      f,
+      proto.cursed.Vec3.serializeBinaryToWriter
+    );
+  }
+  f = message.getPose();
+  if (f != null) {
+    writer.writeMessage(
+      6,
+      f,
+      proto.cursed.Pose.serializeBinaryToWriter
+    );
+  }
+  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 7));
+  if (f != null) {
+    writer.writeBytes(
+      7,
+      f
+    );
+  }
+  f = message.getTestData();
+  if (f != null) {
+    writer.writeMessage(
+      8,
+      f,
+      proto.cursed.TestData.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional string topic = 1;
+ * @return {string}
+ */
+proto.cursed.DataPointMsg.prototype.getTopic = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.setTopic = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional uint64 time = 2;
+ * @return {number}
+ */
+proto.cursed.DataPointMsg.prototype.getTime = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.setTime = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional double scalar = 3;
+ * @return {number}
+ */
+proto.cursed.DataPointMsg.prototype.getScalar = function() {
+  return /** @type {number} */ (jspb.Message.getFloatingPointFieldWithDefault(this, 3, 0.0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.setScalar = function(value) {
+  return jspb.Message.setOneofField(this, 3, proto.cursed.DataPointMsg.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.clearScalar = function() {
+  return jspb.Message.setOneofField(this, 3, proto.cursed.DataPointMsg.oneofGroups_[0], undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.DataPointMsg.prototype.hasScalar = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+/**
+ * optional string text = 4;
+ * @return {string}
+ */
+proto.cursed.DataPointMsg.prototype.getText = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.setText = function(value) {
+  return jspb.Message.setOneofField(this, 4, proto.cursed.DataPointMsg.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.clearText = function() {
+  return jspb.Message.setOneofField(this, 4, proto.cursed.DataPointMsg.oneofGroups_[0], undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.DataPointMsg.prototype.hasText = function() {
+  return jspb.Message.getField(this, 4) != null;
+};
+
+
+/**
+ * optional Vec3 vec3 = 5;
+ * @return {?proto.cursed.Vec3}
+ */
+proto.cursed.DataPointMsg.prototype.getVec3 = function() {
+  return /** @type{?proto.cursed.Vec3} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Vec3, 5));
+};
+
+
+/**
+ * @param {?proto.cursed.Vec3|undefined} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+*/
+proto.cursed.DataPointMsg.prototype.setVec3 = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 5, proto.cursed.DataPointMsg.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.clearVec3 = function() {
+  return this.setVec3(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.DataPointMsg.prototype.hasVec3 = function() {
+  return jspb.Message.getField(this, 5) != null;
+};
+
+
+/**
+ * optional Pose pose = 6;
+ * @return {?proto.cursed.Pose}
+ */
+proto.cursed.DataPointMsg.prototype.getPose = function() {
+  return /** @type{?proto.cursed.Pose} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.Pose, 6));
+};
+
+
+/**
+ * @param {?proto.cursed.Pose|undefined} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+*/
+proto.cursed.DataPointMsg.prototype.setPose = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 6, proto.cursed.DataPointMsg.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.clearPose = function() {
+  return this.setPose(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.DataPointMsg.prototype.hasPose = function() {
+  return jspb.Message.getField(this, 6) != null;
+};
+
+
+/**
+ * optional bytes bytes = 7;
+ * @return {!(string|Uint8Array)}
+ */
+proto.cursed.DataPointMsg.prototype.getBytes = function() {
+  return /** @type {!(string|Uint8Array)} */ (jspb.Message.getFieldWithDefault(this, 7, ""));
+};
+
+
+/**
+ * optional bytes bytes = 7;
+ * This is a type-conversion wrapper around `getBytes()`
+ * @return {string}
+ */
+proto.cursed.DataPointMsg.prototype.getBytes_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getBytes()));
+};
+
+
+/**
+ * optional bytes bytes = 7;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getBytes()`
+ * @return {!Uint8Array}
+ */
+proto.cursed.DataPointMsg.prototype.getBytes_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getBytes()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.setBytes = function(value) {
+  return jspb.Message.setOneofField(this, 7, proto.cursed.DataPointMsg.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.clearBytes = function() {
+  return jspb.Message.setOneofField(this, 7, proto.cursed.DataPointMsg.oneofGroups_[0], undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.DataPointMsg.prototype.hasBytes = function() {
+  return jspb.Message.getField(this, 7) != null;
+};
+
+
+/**
+ * optional TestData test_data = 8;
+ * @return {?proto.cursed.TestData}
+ */
+proto.cursed.DataPointMsg.prototype.getTestData = function() {
+  return /** @type{?proto.cursed.TestData} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.TestData, 8));
+};
+
+
+/**
+ * @param {?proto.cursed.TestData|undefined} value
+ * @return {!proto.cursed.DataPointMsg} returns this
+*/
+proto.cursed.DataPointMsg.prototype.setTestData = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 8, proto.cursed.DataPointMsg.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.DataPointMsg} returns this
+ */
+proto.cursed.DataPointMsg.prototype.clearTestData = function() {
+  return this.setTestData(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.DataPointMsg.prototype.hasTestData = function() {
+  return jspb.Message.getField(this, 8) != null;
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.cursed.DataPointBatch.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.DataPointBatch.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.DataPointBatch.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.DataPointBatch} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.DataPointBatch.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    datapointsList: jspb.Message.toObjectList(msg.getDatapointsList(),
+    proto.cursed.DataPointMsg.toObject, includeInstance)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.DataPointBatch}
+ */
+proto.cursed.DataPointBatch.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.DataPointBatch;
+  return proto.cursed.DataPointBatch.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.DataPointBatch} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.DataPointBatch}
+ */
+proto.cursed.DataPointBatch.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.DataPointMsg;
+      reader.readMessage(value,proto.cursed.DataPointMsg.deserializeBinaryFromReader);
+      msg.addDatapoints(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.DataPointBatch.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.DataPointBatch.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.DataPointBatch} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.DataPointBatch.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDatapointsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.cursed.DataPointMsg.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated DataPointMsg datapoints = 1;
+ * @return {!Array<!proto.cursed.DataPointMsg>}
+ */
+proto.cursed.DataPointBatch.prototype.getDatapointsList = function() {
+  return /** @type{!Array<!proto.cursed.DataPointMsg>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.cursed.DataPointMsg, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.cursed.DataPointMsg>} value
+ * @return {!proto.cursed.DataPointBatch} returns this
+*/
+proto.cursed.DataPointBatch.prototype.setDatapointsList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.cursed.DataPointMsg=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.cursed.DataPointMsg}
+ */
+proto.cursed.DataPointBatch.prototype.addDatapoints = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.cursed.DataPointMsg, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.cursed.DataPointBatch} returns this
+ */
+proto.cursed.DataPointBatch.prototype.clearDatapointsList = function() {
+  return this.setDatapointsList([]);
+};
+
+
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.cursed.UploadChunk.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.cursed.UploadChunk.ChunkCase = {
+  CHUNK_NOT_SET: 0,
+  HEADER: 1,
+  DATA: 2
+};
+
+/**
+ * @return {proto.cursed.UploadChunk.ChunkCase}
+ */
+proto.cursed.UploadChunk.prototype.getChunkCase = function() {
+  return /** @type {proto.cursed.UploadChunk.ChunkCase} */(jspb.Message.computeOneofCase(this, proto.cursed.UploadChunk.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.UploadChunk.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.UploadChunk.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.UploadChunk} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.UploadChunk.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    header: (f = msg.getHeader()) && proto.cursed.UploadHeader.toObject(includeInstance, f),
+    data: msg.getData_asB64()
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.UploadChunk}
+ */
+proto.cursed.UploadChunk.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.UploadChunk;
+  return proto.cursed.UploadChunk.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.UploadChunk} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.UploadChunk}
+ */
+proto.cursed.UploadChunk.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.UploadHeader;
+      reader.readMessage(value,proto.cursed.UploadHeader.deserializeBinaryFromReader);
+      msg.setHeader(value);
+      break;
+    case 2:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setData(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.UploadChunk.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.UploadChunk.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.UploadChunk} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.UploadChunk.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getHeader();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.cursed.UploadHeader.serializeBinaryToWriter
+    );
+  }
+  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 2));
+  if (f != null) {
+    writer.writeBytes(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional UploadHeader header = 1;
+ * @return {?proto.cursed.UploadHeader}
+ */
+proto.cursed.UploadChunk.prototype.getHeader = function() {
+  return /** @type{?proto.cursed.UploadHeader} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.UploadHeader, 1));
+};
+
+
+/**
+ * @param {?proto.cursed.UploadHeader|undefined} value
+ * @return {!proto.cursed.UploadChunk} returns this
+*/
+proto.cursed.UploadChunk.prototype.setHeader = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.cursed.UploadChunk.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.UploadChunk} returns this
+ */
+proto.cursed.UploadChunk.prototype.clearHeader = function() {
+  return this.setHeader(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.UploadChunk.prototype.hasHeader = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional bytes data = 2;
+ * @return {!(string|Uint8Array)}
+ */
+proto.cursed.UploadChunk.prototype.getData = function() {
+  return /** @type {!(string|Uint8Array)} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * optional bytes data = 2;
+ * This is a type-conversion wrapper around `getData()`
+ * @return {string}
+ */
+proto.cursed.UploadChunk.prototype.getData_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getData()));
+};
+
+
+/**
+ * optional bytes data = 2;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getData()`
+ * @return {!Uint8Array}
+ */
+proto.cursed.UploadChunk.prototype.getData_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getData()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.cursed.UploadChunk} returns this
+ */
+proto.cursed.UploadChunk.prototype.setData = function(value) {
+  return jspb.Message.setOneofField(this, 2, proto.cursed.UploadChunk.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.cursed.UploadChunk} returns this
+ */
+proto.cursed.UploadChunk.prototype.clearData = function() {
+  return jspb.Message.setOneofField(this, 2, proto.cursed.UploadChunk.oneofGroups_[0], undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.UploadChunk.prototype.hasData = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.UploadHeader.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.UploadHeader.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.UploadHeader} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.UploadHeader.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    fileName: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.UploadHeader}
+ */
+proto.cursed.UploadHeader.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.UploadHeader;
+  return proto.cursed.UploadHeader.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.UploadHeader} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.UploadHeader}
+ */
+proto.cursed.UploadHeader.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFileName(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.UploadHeader.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.UploadHeader.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.UploadHeader} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.UploadHeader.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getFileName();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string file_name = 1;
+ * @return {string}
+ */
+proto.cursed.UploadHeader.prototype.getFileName = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.UploadHeader} returns this
+ */
+proto.cursed.UploadHeader.prototype.setFileName = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.cursed.DatasetInfo.repeatedFields_ = [6];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.DatasetInfo.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.DatasetInfo.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.DatasetInfo} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.DatasetInfo.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    path: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    format: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    sizeBytes: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    samples: jspb.Message.getFieldWithDefault(msg, 5, 0),
+    keysList: (f = jspb.Message.getRepeatedField(msg, 6)) == null ? undefined : f,
+    startTime: jspb.Message.getFieldWithDefault(msg, 7, 0),
+    endTime: jspb.Message.getFieldWithDefault(msg, 8, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.DatasetInfo}
+ */
+proto.cursed.DatasetInfo.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.DatasetInfo;
+  return proto.cursed.DatasetInfo.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.DatasetInfo} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.DatasetInfo}
+ */
+proto.cursed.DatasetInfo.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setId(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setPath(value);
+      break;
+    case 3:
+      var value = /** @type {!proto.cursed.DatasetFormat} */ (reader.readEnum());
+      msg.setFormat(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setSizeBytes(value);
+      break;
+    case 5:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setSamples(value);
+      break;
+    case 6:
+      var value = /** @type {string} */ (reader.readString());
+      msg.addKeys(value);
+      break;
+    case 7:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setStartTime(value);
+      break;
+    case 8:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setEndTime(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.DatasetInfo.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.DatasetInfo.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.DatasetInfo} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.DatasetInfo.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getPath();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getFormat();
+  if (f !== 0.0) {
+    writer.writeEnum(
+      3,
+      f
+    );
+  }
+  f = message.getSizeBytes();
+  if (f !== 0) {
+    writer.writeUint64(
+      4,
+      f
+    );
+  }
+  f = message.getSamples();
+  if (f !== 0) {
+    writer.writeUint64(
+      5,
+      f
+    );
+  }
+  f = message.getKeysList();
+  if (f.length > 0) {
+    writer.writeRepeatedString(
+      6,
+      f
+    );
+  }
+  f = message.getStartTime();
+  if (f !== 0) {
+    writer.writeUint64(
+      7,
+      f
+    );
+  }
+  f = message.getEndTime();
+  if (f !== 0) {
+    writer.writeUint64(
+      8,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string id = 1;
+ * @return {string}
+ */
+proto.cursed.DatasetInfo.prototype.getId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setId = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string path = 2;
+ * @return {string}
+ */
+proto.cursed.DatasetInfo.prototype.getPath = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setPath = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional DatasetFormat format = 3;
+ * @return {!proto.cursed.DatasetFormat}
+ */
+proto.cursed.DatasetInfo.prototype.getFormat = function() {
+  return /** @type {!proto.cursed.DatasetFormat} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/**
+ * @param {!proto.cursed.DatasetFormat} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setFormat = function(value) {
+  return jspb.Message.setProto3EnumField(this, 3, value);
+};
+
+
+/**
+ * optional uint64 size_bytes = 4;
+ * @return {number}
+ */
+proto.cursed.DatasetInfo.prototype.getSizeBytes = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setSizeBytes = function(value) {
+  return jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional uint64 samples = 5;
+ * @return {number}
+ */
+proto.cursed.DatasetInfo.prototype.getSamples = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setSamples = function(value) {
+  return jspb.Message.setProto3IntField(this, 5, value);
+};
+
+
+/**
+ * repeated string keys = 6;
+ * @return {!Array<string>}
+ */
+proto.cursed.DatasetInfo.prototype.getKeysList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 6));
+};
+
+
+/**
+ * @param {!Array<string>} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setKeysList = function(value) {
+  return jspb.Message.setField(this, 6, value || []);
+};
+
+
+/**
+ * @param {string} value
+ * @param {number=} opt_index
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.addKeys = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 6, value, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.clearKeysList = function() {
+  return this.setKeysList([]);
+};
+
+
+/**
+ * optional uint64 start_time = 7;
+ * @return {number}
+ */
+proto.cursed.DatasetInfo.prototype.getStartTime = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 7, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setStartTime = function(value) {
+  return jspb.Message.setProto3IntField(this, 7, value);
+};
+
+
+/**
+ * optional uint64 end_time = 8;
+ * @return {number}
+ */
+proto.cursed.DatasetInfo.prototype.getEndTime = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 8, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.DatasetInfo} returns this
+ */
+proto.cursed.DatasetInfo.prototype.setEndTime = function(value) {
+  return jspb.Message.setProto3IntField(this, 8, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.UploadResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.UploadResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.UploadResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.UploadResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    dataset: (f = msg.getDataset()) && proto.cursed.DatasetInfo.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.UploadResponse}
+ */
+proto.cursed.UploadResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.UploadResponse;
+  return proto.cursed.UploadResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.UploadResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.UploadResponse}
+ */
+proto.cursed.UploadResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.DatasetInfo;
+      reader.readMessage(value,proto.cursed.DatasetInfo.deserializeBinaryFromReader);
+      msg.setDataset(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.UploadResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.UploadResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.UploadResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.UploadResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDataset();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.cursed.DatasetInfo.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional DatasetInfo dataset = 1;
+ * @return {?proto.cursed.DatasetInfo}
+ */
+proto.cursed.UploadResponse.prototype.getDataset = function() {
+  return /** @type{?proto.cursed.DatasetInfo} */ (
+    jspb.Message.getWrapperField(this, proto.cursed.DatasetInfo, 1));
+};
+
+
+/**
+ * @param {?proto.cursed.DatasetInfo|undefined} value
+ * @return {!proto.cursed.UploadResponse} returns this
+*/
+proto.cursed.UploadResponse.prototype.setDataset = function(value) {
+  return jspb.Message.setWrapperField(this, 1, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.cursed.UploadResponse} returns this
+ */
+proto.cursed.UploadResponse.prototype.clearDataset = function() {
+  return this.setDataset(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.cursed.UploadResponse.prototype.hasDataset = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.ListDatasetsRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.ListDatasetsRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.ListDatasetsRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListDatasetsRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.ListDatasetsRequest}
+ */
+proto.cursed.ListDatasetsRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.ListDatasetsRequest;
+  return proto.cursed.ListDatasetsRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.ListDatasetsRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.ListDatasetsRequest}
+ */
+proto.cursed.ListDatasetsRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.ListDatasetsRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.ListDatasetsRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.ListDatasetsRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListDatasetsRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.cursed.ListDatasetsResponse.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.cursed.ListDatasetsResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.ListDatasetsResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.cursed.ListDatasetsResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListDatasetsResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    datasetsList: jspb.Message.toObjectList(msg.getDatasetsList(),
+    proto.cursed.DatasetInfo.toObject, includeInstance)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.cursed.ListDatasetsResponse}
+ */
+proto.cursed.ListDatasetsResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.cursed.ListDatasetsResponse;
+  return proto.cursed.ListDatasetsResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.cursed.ListDatasetsResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.cursed.ListDatasetsResponse}
+ */
+proto.cursed.ListDatasetsResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.cursed.DatasetInfo;
+      reader.readMessage(value,proto.cursed.DatasetInfo.deserializeBinaryFromReader);
+      msg.addDatasets(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.cursed.ListDatasetsResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.cursed.ListDatasetsResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.cursed.ListDatasetsResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.cursed.ListDatasetsResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDatasetsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.cursed.DatasetInfo.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated DatasetInfo datasets = 1;
+ * @return {!Array<!proto.cursed.DatasetInfo>}
+ */
+proto.cursed.ListDatasetsResponse.prototype.getDatasetsList = function() {
+  return /** @type{!Array<!proto.cursed.DatasetInfo>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.cursed.DatasetInfo, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.cursed.DatasetInfo>} value
+ * @return {!proto.cursed.ListDatasetsResponse} returns this
+*/
+proto.cursed.ListDatasetsResponse.prototype.setDatasetsList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.cursed.DatasetInfo=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.cursed.DatasetInfo}
+ */
+proto.cursed.ListDatasetsResponse.prototype.addDatasets = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.cursed.DatasetInfo, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.cursed.ListDatasetsResponse} returns this
+ */
+proto.cursed.ListDatasetsResponse.prototype.clearDatasetsList = function() {
+  return this.setDatasetsList([]);
+};
+
+
 
 
 
@@ -81,8 +4300,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.cursed.CSVRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.cursed.CSVRequest.toObject(opt_includeInstance, this);
+proto.cursed.QueryRangeRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.QueryRangeRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -91,13 +4310,18 @@ proto.cursed.CSVRequest.prototype.toObject = function(opt_includeInstance) {
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.cursed.CSVRequest} msg The msg instance to transform.
+ * @param {!proto.cursed.QueryRangeRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.cursed.CSVRequest.toObject = function(includeInstance, msg) {
+proto.cursed.QueryRangeRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    path: jspb.Message.getFieldWithDefault(msg, 1, "")
+    path: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    topic: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    tStart: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    tEnd: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    maxPoints: jspb.Message.getFieldWithDefault(msg, 5, 0),
+    downsampling: jspb.Message.getFieldWithDefault(msg, 6, 0)
   };
 
   if (includeInstance) {
@@ -111,23 +4335,23 @@ proto.cursed.CSVRequest.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.cursed.CSVRequest}
+ * @return {!proto.cursed.QueryRangeRequest}
  */
-proto.cursed.CSVRequest.deserializeBinary = function(bytes) {
+proto.cursed.QueryRangeRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.cursed.CSVRequest;
-  return proto.cursed.CSVRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.cursed.QueryRangeRequest;
+  return proto.cursed.QueryRangeRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.cursed.CSVRequest} msg The message object to deserialize into.
+ * @param {!proto.cursed.QueryRangeRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.cursed.CSVRequest}
+ * @return {!proto.cursed.QueryRangeRequest}
  */
-proto.cursed.CSVRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.cursed.QueryRangeRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -138,6 +4362,26 @@ proto.cursed.CSVRequest.deserializeBinaryFromReader = function(msg, reader) {
       var value = /** @type {string} */ (reader.readString());
       msg.setPath(value);
       break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTopic(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setTStart(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setTEnd(value);
+      break;
+    case 5:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setMaxPoints(value);
+      break;
+    case 6:
+      var value = /** @type {!proto.cursed.Downsampling} */ (reader.readEnum());
+      msg.setDownsampling(value);
+      break;
     default:
       reader.skipField();
       break;
@@ -151,9 +4395,9 @@ proto.cursed.CSVRequest.deserializeBinaryFromReader = function(msg, reader) {
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.cursed.CSVRequest.prototype.serializeBinary = function() {
+proto.cursed.QueryRangeRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.cursed.CSVRequest.serializeBinaryToWriter(this, writer);
+  proto.cursed.QueryRangeRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -161,11 +4405,11 @@ proto.cursed.CSVRequest.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.cursed.CSVRequest} message
+ * @param {!proto.cursed.QueryRangeRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.cursed.CSVRequest.serializeBinaryToWriter = function(message, writer) {
+proto.cursed.QueryRangeRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
   f = message.getPath();
   if (f.length > 0) {
@@ -174,6 +4418,41 @@ proto.cursed.CSVRequest.serializeBinaryToWriter = function(message, writer) {
       f
     );
   }
+  f = message.getTopic();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getTStart();
+  if (f !== 0) {
+    writer.writeUint64(
+      3,
+      f
+    );
+  }
+  f = message.getTEnd();
+  if (f !== 0) {
+    writer.writeUint64(
+      4,
+      f
+    );
+  }
+  f = message.getMaxPoints();
+  if (f !== 0) {
+    writer.writeUint32(
+      5,
+      f
+    );
+  }
+  f = message.getDownsampling();
+  if (f !== 0.0) {
+    writer.writeEnum(
+      6,
+      f
+    );
+  }
 };
 
 
@@ -181,20 +4460,117 @@ proto.cursed.CSVRequest.serializeBinaryToWriter = function(message, writer) {
  * optional string path = 1;
  * @return {string}
  */
-proto.cursed.CSVRequest.prototype.getPath = function() {
+proto.cursed.QueryRangeRequest.prototype.getPath = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
  * @param {string} value
- * @return {!proto.cursed.CSVRequest} returns this
+ * @return {!proto.cursed.QueryRangeRequest} returns this
  */
-proto.cursed.CSVRequest.prototype.setPath = function(value) {
+proto.cursed.QueryRangeRequest.prototype.setPath = function(value) {
   return jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
+/**
+ * optional string topic = 2;
+ * @return {string}
+ */
+proto.cursed.QueryRangeRequest.prototype.getTopic = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.cursed.QueryRangeRequest} returns this
+ */
+proto.cursed.QueryRangeRequest.prototype.setTopic = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional uint64 t_start = 3;
+ * @return {number}
+ */
+proto.cursed.QueryRangeRequest.prototype.getTStart = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.QueryRangeRequest} returns this
+ */
+proto.cursed.QueryRangeRequest.prototype.setTStart = function(value) {
+  return jspb.Message.setProto3IntField(this, 3, value);
+};
+
+
+/**
+ * optional uint64 t_end = 4;
+ * @return {number}
+ */
+proto.cursed.QueryRangeRequest.prototype.getTEnd = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.QueryRangeRequest} returns this
+ */
+proto.cursed.QueryRangeRequest.prototype.setTEnd = function(value) {
+  return jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional uint32 max_points = 5;
+ * @return {number}
+ */
+proto.cursed.QueryRangeRequest.prototype.getMaxPoints = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.QueryRangeRequest} returns this
+ */
+proto.cursed.QueryRangeRequest.prototype.setMaxPoints = function(value) {
+  return jspb.Message.setProto3IntField(this, 5, value);
+};
+
+
+/**
+ * optional Downsampling downsampling = 6;
+ * @return {!proto.cursed.Downsampling}
+ */
+proto.cursed.QueryRangeRequest.prototype.getDownsampling = function() {
+  return /** @type {!proto.cursed.Downsampling} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
+};
+
+
+/**
+ * @param {!proto.cursed.Downsampling} value
+ * @return {!proto.cursed.QueryRangeRequest} returns this
+ */
+proto.cursed.QueryRangeRequest.prototype.setDownsampling = function(value) {
+  return jspb.Message.setProto3EnumField(this, 6, value);
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.cursed.QueryRangeResponse.repeatedFields_ = [1];
 
 
 
@@ -211,8 +4587,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.cursed.CSVResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.cursed.CSVResponse.toObject(opt_includeInstance, this);
+proto.cursed.QueryRangeResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.cursed.QueryRangeResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -221,13 +4597,15 @@ proto.cursed.CSVResponse.prototype.toObject = function(opt_includeInstance) {
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.cursed.CSVResponse} msg The msg instance to transform.
+ * @param {!proto.cursed.QueryRangeResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.cursed.CSVResponse.toObject = function(includeInstance, msg) {
+proto.cursed.QueryRangeResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    csvContents: jspb.Message.getFieldWithDefault(msg, 1, "")
+    pointsList: jspb.Message.toObjectList(msg.getPointsList(),
+    proto.cursed.DataPointMsg.toObject, includeInstance),
+    totalPoints: jspb.Message.getFieldWithDefault(msg, 2, 0)
   };
 
   if (includeInstance) {
@@ -241,23 +4619,23 @@ proto.cursed.CSVResponse.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.cursed.CSVResponse}
+ * @return {!proto.cursed.QueryRangeResponse}
  */
-proto.cursed.CSVResponse.deserializeBinary = function(bytes) {
+proto.cursed.QueryRangeResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.cursed.CSVResponse;
-  return proto.cursed.CSVResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.cursed.QueryRangeResponse;
+  return proto.cursed.QueryRangeResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.cursed.CSVResponse} msg The message object to deserialize into.
+ * @param {!proto.cursed.QueryRangeResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.cursed.CSVResponse}
+ * @return {!proto.cursed.QueryRangeResponse}
  */
-proto.cursed.CSVResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.cursed.QueryRangeResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -265,8 +4643,13 @@ proto.cursed.CSVResponse.deserializeBinaryFromReader = function(msg, reader) {
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setCsvContents(value);
+      var value = new proto.cursed.DataPointMsg;
+      reader.readMessage(value,proto.cursed.DataPointMsg.deserializeBinaryFromReader);
+      msg.addPoints(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setTotalPoints(value);
       break;
     default:
       reader.skipField();
@@ -281,9 +4664,9 @@ proto.cursed.CSVResponse.deserializeBinaryFromReader = function(msg, reader) {
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.cursed.CSVResponse.prototype.serializeBinary = function() {
+proto.cursed.QueryRangeResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.cursed.CSVResponse.serializeBinaryToWriter(this, writer);
+  proto.cursed.QueryRangeResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -291,16 +4674,24 @@ proto.cursed.CSVResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.cursed.CSVResponse} message
+ * @param {!proto.cursed.QueryRangeResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.cursed.CSVResponse.serializeBinaryToWriter = function(message, writer) {
+proto.cursed.QueryRangeResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getCsvContents();
+  f = message.getPointsList();
   if (f.length > 0) {
-    writer.writeString(
+    writer.writeRepeatedMessage(
       1,
+      f,
+      proto.cursed.DataPointMsg.serializeBinaryToWriter
+    );
+  }
+  f = message.getTotalPoints();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
       f
     );
   }
@@ -308,21 +4699,79 @@ proto.cursed.CSVResponse.serializeBinaryToWriter = function(message, writer) {
 
 
 /**
- * optional string csv_contents = 1;
- * @return {string}
+ * repeated DataPointMsg points = 1;
+ * @return {!Array<!proto.cursed.DataPointMsg>}
  */
-proto.cursed.CSVResponse.prototype.getCsvContents = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.cursed.QueryRangeResponse.prototype.getPointsList = function() {
+  return /** @type{!Array<!proto.cursed.DataPointMsg>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.cursed.DataPointMsg, 1));
 };
 
 
 /**
- * @param {string} value
- * @return {!proto.cursed.CSVResponse} returns this
+ * @param {!Array<!proto.cursed.DataPointMsg>} value
+ * @return {!proto.cursed.QueryRangeResponse} returns this
+*/
+proto.cursed.QueryRangeResponse.prototype.setPointsList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.cursed.DataPointMsg=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.cursed.DataPointMsg}
  */
-proto.cursed.CSVResponse.prototype.setCsvContents = function(value) {
-  return jspb.Message.setProto3StringField(this, 1, value);
+proto.cursed.QueryRangeResponse.prototype.addPoints = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.cursed.DataPointMsg, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.cursed.QueryRangeResponse} returns this
+ */
+proto.cursed.QueryRangeResponse.prototype.clearPointsList = function() {
+  return this.setPointsList([]);
+};
+
+
+/**
+ * optional uint64 total_points = 2;
+ * @return {number}
+ */
+proto.cursed.QueryRangeResponse.prototype.getTotalPoints = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.cursed.QueryRangeResponse} returns this
+ */
+proto.cursed.QueryRangeResponse.prototype.setTotalPoints = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
+/**
+ * @enum {number}
+ */
+proto.cursed.DatasetFormat = {
+  DATASET_FORMAT_UNKNOWN: 0,
+  DATASET_FORMAT_CSV: 1,
+  DATASET_FORMAT_ARROW_FILE: 2,
+  DATASET_FORMAT_ARROW_STREAM: 3,
+  DATASET_FORMAT_PARQUET: 4,
+  DATASET_FORMAT_MCAP: 5
+};
+
+/**
+ * @enum {number}
+ */
+proto.cursed.Downsampling = {
+  DOWNSAMPLING_MIN_MAX: 0,
+  DOWNSAMPLING_LTTB: 1
+};
+
 goog.object.extend(exports, proto.cursed);